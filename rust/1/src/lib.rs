@@ -0,0 +1,497 @@
+use std::cmp::Ordering;
+use std::iter::zip;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::str::FromStr;
+use aoc_common::Variant;
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 1, name: "sort", solve: part1 },
+    Variant { part: 1, name: "order_statistic", solve: part1_incremental },
+    Variant { part: 2, name: "count", solve: part2 },
+    Variant { part: 2, name: "order_statistic", solve: part2_incremental },
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Metric {
+    Absolute,
+    Squared,
+    // Spearman's footrule: how far apart each row ranks in the two lists
+    Rank,
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Metric, String> {
+        match s {
+            "absolute" => Ok(Metric::Absolute),
+            "squared" => Ok(Metric::Squared),
+            "rank" => Ok(Metric::Rank),
+            _ => Err(format!("Unknown metric {}", s)),
+        }
+    }
+}
+
+// Each whitespace-separated column of the input as its own list
+pub fn columns(lines: impl Iterator<Item = impl AsRef<str>>) -> Vec<Vec<i64>> {
+    let mut columns: Vec<Vec<i64>> = Vec::new();
+    for line in lines {
+        for (ix, n) in line.as_ref().split_whitespace().enumerate() {
+            if ix == columns.len() {
+                columns.push(Vec::new());
+            }
+            columns[ix].push(n.parse::<i64>().unwrap());
+        }
+    }
+
+    return columns;
+}
+
+pub fn parse(contents: &str) -> Vec<Vec<i64>> {
+    return columns(contents.lines());
+}
+
+// Where each row's value falls in its list once sorted, ties broken by row
+fn ranks(list: &[i64]) -> Vec<i64> {
+    let mut order = Vec::from_iter(0..list.len());
+    order.sort_by_key(|ix| list[*ix]);
+    let mut ranks = vec![0; list.len()];
+    for (rank, ix) in order.into_iter().enumerate() {
+        ranks[ix] = rank as i64;
+    }
+
+    return ranks;
+}
+
+/// The pairs compared under `metric` with how much each adds to the distance.
+/// Value metrics pair up the lists once sorted, `Rank` pairs up each row.
+pub fn contributions(a: &[i64], b: &[i64], metric: Metric) -> Vec<(i64, i64, i64)> {
+    if metric == Metric::Rank {
+        return Vec::from_iter(zip(ranks(a), ranks(b)).enumerate().map(|(ix, (ra, rb))| (a[ix], b[ix], (ra - rb).abs())));
+    }
+
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort();
+    b.sort();
+    return Vec::from_iter(zip(a, b).map(|(l, r)| {
+        match metric {
+            Metric::Squared => (l, r, (l - r) * (l - r)),
+            _ => (l, r, (l - r).abs()),
+        }
+    }));
+}
+
+pub fn distance(a: &[i64], b: &[i64], metric: Metric) -> i64 {
+    return contributions(a, b, metric).iter().map(|(_, _, d)| d).sum::<i64>();
+}
+
+pub fn similarity(a: &[i64], b: &[i64]) -> i64 {
+    let mut a_counts: HashMap<i64, i64> = HashMap::new();
+    let mut b_counts: HashMap<i64, i64> = HashMap::new();
+    for n in a {
+        *a_counts.entry(*n).or_insert(0) += 1;
+    }
+    for n in b {
+        *b_counts.entry(*n).or_insert(0) += 1;
+    }
+    return a_counts.iter().map(|(n, count)| n * count * b_counts.get(n).unwrap_or(&0)).sum::<i64>();
+}
+
+pub fn distance_matrix(columns: &[Vec<i64>], metric: Metric) -> Vec<Vec<i64>> {
+    return Vec::from_iter(columns.iter().map(|a| Vec::from_iter(columns.iter().map(|b| distance(a, b, metric)))));
+}
+
+pub fn similarity_matrix(columns: &[Vec<i64>]) -> Vec<Vec<i64>> {
+    return Vec::from_iter(columns.iter().map(|a| Vec::from_iter(columns.iter().map(|b| similarity(a, b)))));
+}
+
+/// The `n` pairs adding most to the distance between `a` and `b`.
+pub fn top_pairs(a: &[i64], b: &[i64], metric: Metric, n: usize) -> Vec<(i64, i64, i64)> {
+    let mut pairs = contributions(a, b, metric);
+    pairs.sort_by_key(|(_, _, d)| -d);
+    pairs.truncate(n);
+    return pairs;
+}
+
+fn print_matrix(title: &str, matrix: &[Vec<i64>]) {
+    println!("\n{}:", title);
+    for row in matrix {
+        println!("{}", row.iter().map(|v| format!("{:>12}", v)).collect::<Vec<String>>().join(""));
+    }
+}
+
+pub fn report(columns: &[Vec<i64>], metric: Metric, top: usize) {
+    print_matrix(&format!("Distances ({:?})", metric), &distance_matrix(columns, metric));
+    print_matrix("Similarity scores", &similarity_matrix(columns));
+    for i in 0..columns.len() {
+        for j in i + 1..columns.len() {
+            for (a, b, d) in top_pairs(&columns[i], &columns[j], metric, top) {
+                println!("Columns {} and {}: {} vs {} adds {}", i + 1, j + 1, a, b, d);
+            }
+        }
+    }
+}
+
+/// Point updates and prefix sums in O(log n).
+struct Fenwick {
+    tree: Vec<i64>,
+}
+
+impl Fenwick {
+    fn new(len: usize) -> Fenwick {
+        return Fenwick { tree: vec![0; len + 1] };
+    }
+
+    fn add(&mut self, ix: usize, delta: i64) {
+        let mut ix = ix + 1;
+        while ix < self.tree.len() {
+            self.tree[ix] += delta;
+            ix += ix & ix.wrapping_neg();
+        }
+    }
+
+    // Sum of the first ix entries
+    fn prefix(&self, ix: usize) -> i64 {
+        let mut total = 0;
+        let mut ix = ix;
+        while ix > 0 {
+            total += self.tree[ix];
+            ix -= ix & ix.wrapping_neg();
+        }
+        return total;
+    }
+
+    // The most entries whose sum stays within target, for non-negative entries
+    fn search(&self, target: i64) -> usize {
+        let mut pos = 0;
+        let mut remaining = target;
+        let mut step = (self.tree.len() - 1).next_power_of_two();
+        while step > 0 {
+            if pos + step < self.tree.len() && self.tree[pos + step] <= remaining {
+                pos += step;
+                remaining -= self.tree[pos];
+            }
+            step /= 2;
+        }
+        return pos;
+    }
+}
+
+/// A multiset of values in `0..bound` with rank and select in O(log bound),
+/// kept as Fenwick trees of counts and of value sums.
+struct OrderStatistic {
+    tree: Fenwick,
+    sums: Fenwick,
+    counts: Vec<i64>,
+    len: usize,
+}
+
+impl OrderStatistic {
+    fn new(bound: usize) -> OrderStatistic {
+        return OrderStatistic { tree: Fenwick::new(bound), sums: Fenwick::new(bound), counts: vec![0; bound], len: 0 };
+    }
+
+    fn add(&mut self, value: usize, delta: i64) {
+        self.counts[value] += delta;
+        self.len = (self.len as i64 + delta) as usize;
+        self.tree.add(value, delta);
+        self.sums.add(value, delta * value as i64);
+    }
+
+    // How many values are smaller than value
+    fn rank(&self, value: usize) -> usize {
+        return self.tree.prefix(value) as usize;
+    }
+
+    // The kth smallest value, counting from 0
+    fn select(&self, k: usize) -> usize {
+        return self.tree.search(k as i64);
+    }
+
+    // How many of the `cap` smallest values are smaller than value, and their sum
+    fn below(&self, value: usize, cap: usize) -> (i64, i64) {
+        let count = self.rank(value).min(cap);
+        if count == 0 {
+            return (0, 0);
+        }
+        let last = self.select(count - 1);
+        return (count as i64, self.sums.prefix(last) + (count - self.rank(last)) as i64 * last as i64);
+    }
+
+    // Sums, over the thresholds in from..to, how many of the `cap` smallest
+    // values are at most the threshold. A value at or below from counts at
+    // every threshold and one inside the range from its own value on.
+    fn area(&self, from: usize, to: usize, cap: usize) -> i64 {
+        let (at_from, from_sum) = self.below(from + 1, cap);
+        let (to_count, to_sum) = self.below(to, cap);
+        return (to - from) as i64 * at_from + to as i64 * (to_count - at_from) - (to_sum - from_sum);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Both lists as order-statistic multisets, so the distance and similarity
+/// can be kept up to date as values come and go instead of sorting again.
+///
+/// Each pair of the sorted lists adds one to the distance for every threshold
+/// that exactly one of its values is at most, so the distance is the gap
+/// between how many of each list's paired values are at most each threshold,
+/// summed over thresholds. Over a stretch of thresholds where one list stays
+/// ahead that sum has a closed form from the count and sum trees, so a change
+/// only walks the stretches above the changed value, at O(log bound) each.
+/// That's O(n log bound) in the worst case, when the lists keep overtaking
+/// each other. The similarity changes by the value times its count in the
+/// other list.
+pub struct Lists {
+    values: Range<i64>,
+    left: OrderStatistic,
+    right: OrderStatistic,
+    distance: i64,
+    similarity: i64,
+}
+
+impl Lists {
+    /// Lists that can hold values in `values`.
+    pub fn new(values: Range<i64>) -> Lists {
+        let bound = (values.end - values.start).max(0) as usize;
+        return Lists { values, left: OrderStatistic::new(bound), right: OrderStatistic::new(bound), distance: 0, similarity: 0 };
+    }
+
+    /// Lists starting out with `left` and `right`, paired up once rather than
+    /// a value at a time.
+    pub fn with(values: Range<i64>, left: &[i64], right: &[i64]) -> Result<Lists, String> {
+        let mut lists = Lists::new(values);
+        for l in left {
+            let slot = lists.slot(*l)?;
+            lists.left.add(slot, 1);
+        }
+        for r in right {
+            let slot = lists.slot(*r)?;
+            lists.right.add(slot, 1);
+        }
+        lists.distance = lists.tail(0);
+        lists.similarity = (0..lists.left.counts.len()).map(|slot| {
+            (lists.values.start + slot as i64) * lists.left.counts[slot] * lists.right.counts[slot]
+        }).sum::<i64>();
+        return Ok(lists);
+    }
+
+    pub fn distance(&self) -> i64 {
+        return self.distance;
+    }
+
+    pub fn similarity(&self) -> i64 {
+        return self.similarity;
+    }
+
+    // Where value is kept in the trees, which only hold values in range
+    fn slot(&self, value: i64) -> Result<usize, String> {
+        if !self.values.contains(&value) {
+            return Err(format!("{} is outside {}..{}", value, self.values.start, self.values.end));
+        }
+        return Ok((value - self.values.start) as usize);
+    }
+
+    // Distance between the sorted lists over the thresholds from `from` up
+    fn tail(&self, from: usize) -> i64 {
+        let paired = self.left.len.min(self.right.len);
+        let bound = self.left.counts.len();
+        let mut total = 0;
+        let mut start = from;
+        while start < bound {
+            let ahead = self.left.rank(start + 1).min(paired) >= self.right.rank(start + 1).min(paired);
+            let (lead, lag) = if ahead { (&self.left, &self.right) } else { (&self.right, &self.left) };
+            let overtaken = |lead_count: usize, lag_count: usize| if ahead { lag_count > lead_count } else { lag_count >= lead_count };
+
+            // Jump to where the lagging list has caught up with the lead so
+            // far, until it passes it
+            let mut end = start;
+            loop {
+                let (lead_count, lag_count) = (lead.rank(end + 1).min(paired), lag.rank(end + 1).min(paired));
+                if end > start && overtaken(lead_count, lag_count) {
+                    break;
+                }
+                let needed = if ahead { lead_count + 1 } else { lead_count };
+                if needed > paired {
+                    end = bound;
+                    break;
+                }
+                end = lag.select(needed - 1);
+            }
+
+            total += (self.left.area(start, end, paired) - self.right.area(start, end, paired)).abs();
+            start = end;
+        }
+
+        return total;
+    }
+
+    fn update(&mut self, side: Side, value: i64, delta: i64) {
+        let slot = (value - self.values.start) as usize;
+        let (list, other) = match side {
+            Side::Left => (&self.left, &self.right),
+            Side::Right => (&self.right, &self.left),
+        };
+        self.similarity += delta * value * other.counts[slot];
+
+        // Changing the shorter list also pairs up or drops the other list's
+        // next value, which moves the thresholds from there up too
+        let paired = list.len.min(other.len);
+        let from = match (delta > 0, list.len.cmp(&other.len)) {
+            (true, Ordering::Less) => slot.min(other.select(paired)),
+            (false, Ordering::Less | Ordering::Equal) => slot.min(other.select(paired - 1)),
+            _ => slot,
+        };
+
+        self.distance -= self.tail(from);
+        match side {
+            Side::Left => self.left.add(slot, delta),
+            Side::Right => self.right.add(slot, delta),
+        }
+        self.distance += self.tail(from);
+    }
+
+    pub fn insert(&mut self, side: Side, value: i64) -> Result<(), String> {
+        self.slot(value)?;
+        self.update(side, value, 1);
+        return Ok(());
+    }
+
+    /// Removes one copy of `value`, returning whether there was one.
+    pub fn remove(&mut self, side: Side, value: i64) -> bool {
+        let list = match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        };
+        match self.slot(value) {
+            Ok(slot) if list.counts[slot] > 0 => (),
+            _ => return false,
+        }
+
+        self.update(side, value, -1);
+        return true;
+    }
+}
+
+// Columns missing from the input are empty lists
+fn incremental(contents: &str) -> Lists {
+    let columns = parse(contents);
+    let values = columns.iter().flatten();
+    let low = *values.clone().min().unwrap_or(&0);
+    let high = *values.max().unwrap_or(&-1);
+    let column = |ix: usize| columns.get(ix).map_or(&[][..], |column| &column[..]);
+    return Lists::with(low..high + 1, column(0), column(1)).unwrap();
+}
+
+fn part1_incremental(contents: &str) -> i64 {
+    return incremental(contents).distance();
+}
+
+fn part2_incremental(contents: &str) -> i64 {
+    return incremental(contents).similarity();
+}
+
+pub fn part1_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    let columns = columns(lines);
+    return distance(&columns[0], &columns[1], Metric::Absolute);
+}
+
+pub fn part2_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    let columns = columns(lines);
+    return similarity(&columns[0], &columns[1]);
+}
+
+pub fn part1(contents: &str) -> i64 {
+    return part1_lines(contents.lines());
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return part2_lines(contents.lines());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 11);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 31);
+    }
+
+    #[test]
+    fn columns_test() {
+        let columns = columns("3   4   3\n4   3   4\n2   5   2\n1   3   1\n3   9   3\n3   3   3".lines());
+        assert_eq!(distance_matrix(&columns, Metric::Absolute), vec![vec![0, 11, 0], vec![11, 0, 11], vec![0, 11, 0]]);
+        assert_eq!(similarity_matrix(&columns)[0], vec![34, 31, 34]);
+        assert_eq!(distance(&columns[0], &columns[1], Metric::Squared), 35);
+        assert_eq!(distance(&columns[0], &columns[1], Metric::Rank), 14);
+        assert_eq!(top_pairs(&columns[0], &columns[1], Metric::Absolute, 2), vec![(4, 9, 5), (1, 3, 2)]);
+    }
+
+    #[test]
+    fn incremental_test() {
+        let mut lists = Lists::new(0..10);
+        for (l, r) in [(3, 4), (4, 3), (2, 5), (1, 3), (3, 9), (3, 3)] {
+            lists.insert(Side::Left, l).unwrap();
+            lists.insert(Side::Right, r).unwrap();
+        }
+        assert_eq!((lists.distance(), lists.similarity()), (11, 31));
+        assert_eq!(part1_incremental("3   4\n4   3\n2   5\n1   3\n3   9\n3   3"), 11);
+
+        assert!(lists.remove(Side::Right, 9));
+        assert!(!lists.remove(Side::Right, 9));
+        lists.insert(Side::Right, 1).unwrap();
+        assert_eq!((lists.distance(), lists.similarity()), (part1("3 4\n4 3\n2 5\n1 3\n3 1\n3 3"), 32));
+
+        lists.insert(Side::Left, 5).unwrap();
+        assert_eq!((lists.distance(), lists.similarity()), (3, 37));
+        assert!(lists.remove(Side::Left, 3));
+        assert_eq!(lists.distance(), part1("4 4\n2 3\n1 5\n3 3\n3 1\n5 3"));
+
+        assert!(lists.insert(Side::Left, 10).is_err());
+        assert!(lists.insert(Side::Left, -1).is_err());
+        assert!(!lists.remove(Side::Left, -1));
+        assert_eq!(part1_incremental("-3   4\n4   -3"), part1("-3   4\n4   -3"));
+        assert_eq!((part1_incremental(""), part2_incremental("1\n2")), (0, 0));
+    }
+
+    #[test]
+    fn incremental_matches_sort_test() {
+        let mut lists = Lists::new(-20..20);
+        let (mut left, mut right): (Vec<i64>, Vec<i64>) = (Vec::new(), Vec::new());
+        let mut seed: u64 = 1;
+        for _ in 0..2000 {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            let value = (seed >> 33) as i64 % 40 - 20;
+            let (side, list) = if seed >> 62 & 1 == 0 { (Side::Left, &mut left) } else { (Side::Right, &mut right) };
+            if seed >> 63 == 0 || list.len() < 3 {
+                lists.insert(side, value).unwrap();
+                list.push(value);
+            } else if let Some(ix) = list.iter().position(|v| *v == value) {
+                assert!(lists.remove(side, value));
+                list.remove(ix);
+            } else {
+                assert!(!lists.remove(side, value));
+            }
+            assert_eq!((lists.distance(), lists.similarity()), (distance(&left, &right, Metric::Absolute), similarity(&left, &right)));
+        }
+    }
+}
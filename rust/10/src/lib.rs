@@ -0,0 +1,82 @@
+use std::collections::{HashMap, VecDeque};
+
+fn trailhead_counts(start_pos: (i64, i64), heights: &HashMap<(i64, i64), i64>) -> Vec<i64> {
+    let mut to_check: VecDeque<(i64, i64)> = VecDeque::from([start_pos]);
+    let mut count: HashMap<(i64, i64), i64> = HashMap::new();
+
+    while let Some(pos) = to_check.pop_front() {
+        *count.entry(pos).or_insert(0) += 1;
+        let curr_height: i64 = *heights.get(&pos).unwrap();
+
+        for (dx, dy) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+            let new_pos: (i64, i64) = (pos.0 + dx, pos.1 + dy);
+            if heights.get(&new_pos).unwrap_or(&-1) - curr_height == 1 {
+                to_check.push_back(new_pos);
+            }
+        }
+    }
+
+    return Vec::from_iter(count.iter().filter_map(|(k, v)| {
+        if *heights.get(k).unwrap() == 9 {
+            Some(*v)
+        } else {
+            None
+        }
+    }));
+}
+
+pub fn parse(contents: &str) -> HashMap<(i64, i64), i64> {
+    let mut heights: HashMap<(i64, i64), i64> = HashMap::new();
+    for (y, line) in contents.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            heights.insert((x as i64, y as i64), (c as u32 - '0' as u32) as i64);
+        }
+    }
+    return heights;
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let heights = parse(contents);
+
+    return heights.iter().filter_map(|(pos, h)| {
+        if *h == 0 {
+            Some(trailhead_counts(*pos, &heights).len() as i64)
+        } else {
+            None
+        }
+    }).sum::<i64>();
+}
+
+pub fn part2(contents: &str) -> i64 {
+    let heights = parse(contents);
+
+    return heights.iter().filter_map(|(pos, h)| {
+        if *h == 0 {
+            Some(trailhead_counts(*pos, &heights).iter().sum::<i64>())
+        } else {
+            None
+        }
+    }).sum::<i64>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 36);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 81);
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use aoc_common::overflow;
+
+fn count_blinks(stones: HashMap<i64, i64>, blinks: i64) -> i64 {
+    if blinks == 0 {
+        return overflow::sum(stones.values().copied());
+    }
+
+    let mut next_stones: HashMap<i64, i64> = HashMap::new();
+    for (stone_val, amt) in stones.iter() {
+        let val_str = format!("{}", stone_val);
+        for next_val in if *stone_val == 0 {
+            vec![1]
+        } else if val_str.len() % 2 == 0 {
+            vec![val_str[..val_str.len() / 2].parse::<i64>().unwrap(), val_str[val_str.len() / 2..].parse::<i64>().unwrap()]
+        } else {
+            vec![overflow::mul(*stone_val, 2024)]
+        } {
+            let count = next_stones.entry(next_val).or_insert(0);
+            *count = overflow::add(*count, *amt);
+        }
+    }
+
+    return count_blinks(next_stones, blinks - 1);
+}
+
+/// How many stones carry each number.
+pub fn parse(contents: &str) -> HashMap<i64, i64> {
+    let mut stone_map: HashMap<i64, i64> = HashMap::new();
+    for n in contents.lines().next().unwrap().split_whitespace() {
+        *stone_map.entry(n.parse::<i64>().unwrap()).or_insert(0) += 1;
+    }
+    return stone_map;
+}
+
+pub fn part1(contents: &str, blinks: i64) -> i64 {
+    return count_blinks(parse(contents), blinks);
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return count_blinks(parse(contents), 75);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents, 6), 22);
+        assert_eq!(part1(&contents, 25), 55312);
+    }
+
+    #[test]
+    #[cfg(feature = "checked")]
+    #[should_panic(expected = "Overflow computing")]
+    fn overflow_test() {
+        part1("125 17", 500);
+    }
+}
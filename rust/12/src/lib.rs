@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use aoc_common::Variant;
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 1, name: "rebuild", solve: part1 },
+    Variant { part: 1, name: "flood_fill", solve: part1_flood_fill },
+    Variant { part: 2, name: "rebuild", solve: part2 },
+    Variant { part: 2, name: "corners", solve: part2_corners },
+];
+
+fn contiguous_area(plots: &HashMap<(i64, i64), char>, start_pos: (i64, i64)) -> HashMap<(i64, i64), HashSet<(i64, i64)>> {
+    let test_char: char = *plots.get(&start_pos).unwrap();
+    let mut area: HashMap<(i64, i64), HashSet<(i64, i64)>> = HashMap::new();
+    let mut checking: VecDeque<(i64, i64)> = VecDeque::from([start_pos]);
+
+    while let Some(pos) = checking.pop_front() {
+        if area.contains_key(&pos) {
+            continue;
+        }
+
+        let mut neighbors: HashSet<(i64, i64)> = HashSet::new();
+        for neighbor_dir in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+            let new_pos = (pos.0 + neighbor_dir.0, pos.1 + neighbor_dir.1);
+            if *plots.get(&new_pos).unwrap_or(&' ') == test_char {
+                checking.push_back(new_pos);
+            } else {
+                neighbors.insert(neighbor_dir);
+            }
+        }
+
+        area.insert(pos, neighbors);
+    }
+
+    return area;
+}
+
+pub fn parse(contents: &str) -> HashMap<(i64, i64), char> {
+    let mut plots: HashMap<(i64, i64), char> = HashMap::new();
+    for (y, line) in contents.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            plots.insert((x as i64, y as i64), c);
+        }
+    }
+    return plots;
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let mut plots = parse(contents);
+
+    let mut total_price: i64 = 0;
+    while plots.len() > 0 {
+        let area = contiguous_area(&plots, *plots.keys().next().unwrap());
+        total_price += area.len() as i64 * area.values().map(|v| v.len() as i64).sum::<i64>();
+        plots = HashMap::from_iter(plots.iter().filter_map(|(k, v)| if !area.contains_key(k) {Some((*k, *v))} else {None}));
+    }
+    
+    return total_price;
+}
+
+pub fn part2(contents: &str) -> i64 {
+    let mut plots = parse(contents);
+
+    let mut total_price: i64 = 0;
+    while plots.len() > 0 {
+        let area = contiguous_area(&plots, *plots.keys().next().unwrap());
+        let mut side_directions: HashMap<(i64, i64), HashSet<(i64, i64)>> = HashMap::new();
+
+        for (pos, offset_directions) in area.iter() {
+            for offset in offset_directions.iter() {
+                side_directions.entry(*offset).or_insert(HashSet::new()).insert((pos.0 + offset.0, pos.1 + offset.1));
+            }
+        }
+
+        let mut sides: i64 = 0;
+        
+        for outside in side_directions.values() {
+            let mut area_plots: HashMap<(i64, i64), char> = HashMap::from_iter(outside.iter().map(|k| (*k, '0')));
+
+            while area_plots.len() > 0 {
+                let side = contiguous_area(&area_plots, *area_plots.keys().next().unwrap());
+                area_plots = HashMap::from_iter(area_plots.iter().filter_map(|(k, v)| if !side.contains_key(k) {Some((*k, *v))} else {None}));
+                sides += 1;
+            }
+        }
+
+        total_price += sides * area.len() as i64;
+        plots = HashMap::from_iter(plots.iter().filter_map(|(k, v)| if !area.contains_key(k) {Some((*k, *v))} else {None}));
+    }
+    
+    return total_price;
+}
+
+/// Each plot in a region, with the directions it's fenced off in.
+type Region = HashMap<(i64, i64), HashSet<(i64, i64)>>;
+
+// Flood fills each region once, skipping plots already seen instead of
+// rebuilding the remaining plots after every region
+fn regions(contents: &str) -> Vec<Region> {
+    let plots = parse(contents);
+
+    let mut seen: HashSet<(i64, i64)> = HashSet::new();
+    let mut regions: Vec<Region> = Vec::new();
+    for (y, line) in contents.lines().enumerate() {
+        for x in 0..line.len() {
+            let pos = (x as i64, y as i64);
+            if seen.insert(pos) {
+                let area = contiguous_area(&plots, pos);
+                seen.extend(area.keys());
+                regions.push(area);
+            }
+        }
+    }
+
+    return regions;
+}
+
+fn part1_flood_fill(contents: &str) -> i64 {
+    return regions(contents).iter().map(|area| {
+        area.len() as i64 * area.values().map(|v| v.len() as i64).sum::<i64>()
+    }).sum::<i64>();
+}
+
+// A region has as many sides as it has corners
+fn part2_corners(contents: &str) -> i64 {
+    return regions(contents).iter().map(|area| {
+        let corners = area.keys().map(|pos| {
+            [(1, 1), (1, -1), (-1, 1), (-1, -1)].iter().filter(|(dx, dy)| {
+                let side_x = area.contains_key(&(pos.0 + dx, pos.1));
+                let side_y = area.contains_key(&(pos.0, pos.1 + dy));
+                let diagonal = area.contains_key(&(pos.0 + dx, pos.1 + dy));
+                (!side_x && !side_y) || (side_x && side_y && !diagonal)
+            }).count() as i64
+        }).sum::<i64>();
+        area.len() as i64 * corners
+    }).sum::<i64>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let mut contents =
+            fs::read_to_string("example1.txt").expect("Should have been able to read the file");
+        assert_eq!(part1(&contents), 140);
+
+        contents =
+            fs::read_to_string("example2.txt").expect("Should have been able to read the file");
+        assert_eq!(part1(&contents), 772);
+
+        contents =
+            fs::read_to_string("example3.txt").expect("Should have been able to read the file");
+        assert_eq!(part1(&contents), 1930);
+    }
+
+    #[test]
+    fn p2_test() {
+        let mut contents =
+            fs::read_to_string("example1.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&contents), 80);
+
+        contents =
+            fs::read_to_string("example2.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&contents), 436);
+
+        contents =
+            fs::read_to_string("example3.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&contents), 1206);
+
+        contents =
+            fs::read_to_string("example4.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&contents), 236);
+            
+        contents =
+            fs::read_to_string("example5.txt").expect("Should have been able to read the file");
+        assert_eq!(part2(&contents), 368);
+    }
+
+    #[test]
+    fn variants_test() {
+        let timings = aoc_common::time_variants("AAAA\nBBCD\nBBCC\nEEEC", VARIANTS);
+        assert!(aoc_common::agree(&timings));
+        assert_eq!(timings.iter().map(|t| t.answer.as_str()).collect::<Vec<&str>>(), vec!["140", "140", "80", "80"]);
+
+        let contents = "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA";
+        assert!(aoc_common::agree(&aoc_common::time_variants(contents, VARIANTS)));
+    }
+}
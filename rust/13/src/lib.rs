@@ -0,0 +1,115 @@
+use regex::Regex;
+use std::iter::zip;
+use aoc_common::{overflow, Assumption};
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption { name: "non-collinear buttons", check: non_collinear_buttons },
+];
+
+#[derive(Debug)]
+pub struct Claw {
+    pub a: (f64, f64),
+    pub b: (f64, f64),
+    pub prize: (f64, f64),
+}
+
+impl Claw {
+    fn new(group: &str) -> Claw {
+        let re = Regex::new(r"-?\d+").unwrap();
+        let mut nums = re.find_iter(group);
+
+        Claw {
+            a: (nums.next().unwrap().as_str().parse::<f64>().unwrap(), nums.next().unwrap().as_str().parse::<f64>().unwrap()),
+            b: (nums.next().unwrap().as_str().parse::<f64>().unwrap(), nums.next().unwrap().as_str().parse::<f64>().unwrap()),
+            prize: (nums.next().unwrap().as_str().parse::<f64>().unwrap(), nums.next().unwrap().as_str().parse::<f64>().unwrap()),
+        }
+    }
+
+    fn tokens(&self) -> Option<(i64, i64)> {
+        let mut matrix: Vec<Vec<f64>> = vec![
+            vec![self.a.0, self.b.0, self.prize.0],
+            vec![self.a.1, self.b.1, self.prize.1],
+        ];
+
+        for i in 0..matrix.len() {
+            // Scale current row
+            let scale: f64 = matrix[i][i];
+            matrix[i] = Vec::from_iter(matrix[i].iter().map(|n| *n / scale));
+
+            // Reduce other rows
+            for j in (0..matrix.len()).filter(|j| *j != i) {
+                let sub_mult = matrix[j][i];
+                matrix[j] = Vec::from_iter(zip(matrix[j].clone(), matrix[i].clone()).map(|(v_j, v_i)| v_j - sub_mult * v_i));
+            }
+        }
+
+        if matrix.iter().all(|r| {
+            let n = r[r.len() - 1].round();
+            (r[r.len() - 1] - n).abs() < 0.001
+        }) {
+            Some((overflow::round(matrix[0][2]), overflow::round(matrix[1][2])))
+        } else {
+            None
+        }
+    }
+}
+
+pub fn parse(contents: &str) -> Vec<Claw> {
+    return Vec::from_iter(contents.split("\n\n").map(Claw::new));
+}
+
+fn non_collinear_buttons(contents: &str) -> Result<(), String> {
+    for (ix, c) in parse(contents).iter().enumerate() {
+        if c.a.0 * c.b.1 - c.a.1 * c.b.0 == 0.0 {
+            return Err(format!("machine {} has collinear buttons {:?} and {:?}", ix, c.a, c.b));
+        }
+    }
+
+    return Ok(());
+}
+
+pub fn part1(contents: &str) -> i64 {
+    return overflow::sum(parse(contents).into_iter().filter_map(|c| {
+        if let Some((a, b)) = c.tokens() {
+            if a.max(b) <= 100 {
+                Some(overflow::add(overflow::mul(a, 3), b))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }));
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return overflow::sum(parse(contents).into_iter().filter_map(|mut c| {
+        c.prize = (c.prize.0 + 10000000000000.0, c.prize.1 + 10000000000000.0);
+
+        if let Some((a, b)) = c.tokens() {
+            Some(overflow::add(overflow::mul(a, 3), b))
+        } else {
+            None
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 480);
+    }
+
+    #[test]
+    fn collinear_test() {
+        assert!(non_collinear_buttons("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400").is_ok());
+        assert!(non_collinear_buttons("Button A: X+2, Y+4\nButton B: X+1, Y+2\nPrize: X=10, Y=20").is_err());
+    }
+}
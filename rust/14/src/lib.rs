@@ -0,0 +1,149 @@
+use regex::Regex;
+use std::collections::HashMap;
+use mod_exp::mod_exp;
+use std::iter::zip;
+use aoc_common::overflow;
+
+pub const WIDTH: i64 = 101;
+pub const HEIGHT: i64 = 103;
+
+fn is_prime(n: i64) -> bool {
+    return n > 1 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0);
+}
+
+// The CRT in part 2 inverts each dimension with Fermat's little theorem
+pub fn prime_dimensions(width: i64, height: i64) -> Result<(), String> {
+    return match [width, height].into_iter().find(|n| !is_prime(*n)) {
+        Some(n) => Err(format!("{} is not prime", n)),
+        None => Ok(()),
+    };
+}
+
+pub fn robots_on_grid(contents: &str, width: i64, height: i64) -> Result<(), String> {
+    return match parse(contents).into_iter().find(|r| !(0..width).contains(&r.p_x) || !(0..height).contains(&r.p_y)) {
+        Some(r) => Err(format!("robot at {},{} is outside the {}x{} grid", r.p_x, r.p_y, width, height)),
+        None => Ok(()),
+    };
+}
+
+#[derive(Debug)]
+pub struct Robot {
+    pub p_x: i64,
+    pub p_y: i64,
+    pub v_x: i64,
+    pub v_y: i64,
+}
+
+impl Robot {
+    fn new(line: &str) -> Robot {
+        let re = Regex::new(r"-?\d+").unwrap();
+        let mut nums = re.find_iter(line);
+
+        Robot {
+            p_x: nums.next().unwrap().as_str().parse::<i64>().unwrap(),
+            p_y: nums.next().unwrap().as_str().parse::<i64>().unwrap(),
+            v_x: nums.next().unwrap().as_str().parse::<i64>().unwrap(),
+            v_y: nums.next().unwrap().as_str().parse::<i64>().unwrap(),
+        }
+    }
+
+    fn move_robot(&mut self, width: i64, height: i64) {
+        self.p_x = (self.p_x + self.v_x + width) % width;
+        self.p_y = (self.p_y + self.v_y + height) % height;
+    }
+
+    fn quadrant(&self, width: i64, height: i64) -> Option<i64> {
+        if self.p_x == width / 2 || self.p_y == height / 2 {
+            None
+        } else {
+            Some((((self.p_y > height / 2) as i64) << 1) + ((self.p_x > width / 2) as i64))
+        }
+    }
+}
+
+pub fn parse(contents: &str) -> Vec<Robot> {
+    return Vec::from_iter(contents.lines().map(Robot::new));
+}
+
+pub fn part1(contents: &str, width: i64, height: i64) -> i64 {
+    let mut robots = parse(contents);
+
+    for _ in 0..100 {
+        for mut robot in &mut robots {
+            robot.move_robot(width, height);
+        }
+    }
+
+    let mut robot_quadrants: HashMap<i64, Vec<Robot>> = HashMap::new();
+    for robot in robots {
+        if let Some(q) = robot.quadrant(width, height) {
+            robot_quadrants.entry(q).or_insert(Vec::new()).push(robot);
+        }
+    }
+    
+    return overflow::product(robot_quadrants.values().map(|v| v.len() as i64));
+}
+
+pub fn part2(contents: &str, width: i64, height: i64) -> i64 {
+    let mut robots = parse(contents);
+
+    let mut min_x_var: f64 = f64::INFINITY;
+    let mut min_y_var: f64 = f64::INFINITY;
+    let mut min_x_t: i64 = -1;
+    let mut min_y_t: i64 = -1;
+
+    for t in 0..width.max(height) {
+        let mean_x = robots.iter().map(|r| r.p_x as f64).sum::<f64>() / (robots.len() as f64);
+        let mean_y = robots.iter().map(|r| r.p_y as f64).sum::<f64>() / (robots.len() as f64);
+        let var_x = robots.iter().map(|r| (r.p_x as f64 - mean_x).powf(2.0)).sum::<f64>() / (robots.len() as f64);
+        let var_y = robots.iter().map(|r| (r.p_y as f64 - mean_y).powf(2.0)).sum::<f64>() / (robots.len() as f64);
+        
+        if var_x < min_x_var {
+            min_x_var = var_x;
+            min_x_t = t as i64;
+        }
+        
+        if var_y < min_y_var {
+            min_y_var = var_y;
+            min_y_t = t as i64;
+        }
+
+        for mut robot in &mut robots {
+            robot.move_robot(width, height);
+        }
+    }
+
+    // Chinese Remainder Theorem for the answer
+    let n_s: Vec<i64> = vec![width, height];
+    let big_n = overflow::product(n_s.iter().copied());
+    let a_s: Vec<i64> = vec![min_x_t, min_y_t];
+    let y_s: Vec<i64> = Vec::from_iter(n_s.iter().map(|n| big_n / n));
+    let z_s: Vec<i64> = Vec::from_iter(zip(y_s.clone(), n_s.clone()).map(|(y, m)| mod_exp(y, m - 2, m)));
+
+    return overflow::sum(zip(a_s, zip(y_s, z_s)).map(|(a, (y, z))| overflow::mul(overflow::mul(a, y), z))) % big_n;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents, 11, 7), 12);
+    }
+
+    #[test]
+    fn robots_on_grid_test() {
+        assert!(robots_on_grid("p=0,4 v=3,-3\np=100,102 v=-1,2", WIDTH, HEIGHT).is_ok());
+        assert!(robots_on_grid("p=101,4 v=3,-3", WIDTH, HEIGHT).is_err());
+        assert!(robots_on_grid("p=10,6 v=3,-3", 11, 7).is_ok());
+        assert!(robots_on_grid("p=100,102 v=-1,2", 11, 7).is_err());
+        assert!(prime_dimensions(WIDTH, HEIGHT).is_ok());
+        assert_eq!(prime_dimensions(101, 100), Err("100 is not prime".to_string()));
+        assert!(!is_prime(100) && is_prime(7));
+    }
+}
@@ -0,0 +1,61 @@
+use cached::proc_macro::cached;
+
+#[cached]
+fn num_combos(pattern: String, possible_patterns: Vec<String>, min_len: usize, max_len: usize) -> Option<i64> {
+    if pattern.len() == 0 {
+        return Some(1);
+    }
+
+    let mut num_possible: Option<i64> = None;
+    for slice_len in (min_len..=max_len).rev().filter(|s| *s <= pattern.len() && possible_patterns.contains(&pattern[..*s].to_string())) {
+        if let Some(num) = num_combos(pattern[slice_len..].to_string(), possible_patterns.clone(), min_len, max_len) {
+            num_possible = Some(num_possible.unwrap_or(0) + num);
+        }
+    }
+    return num_possible;
+}
+
+/// The available towel patterns and the designs to make from them.
+pub fn parse(contents: &str) -> (Vec<String>, Vec<String>) {
+    let mut input_groups = contents.split("\n\n");
+    let possible_patterns = Vec::from_iter(input_groups.next().unwrap().split(", ").map(|p| p.to_string()));
+    let designs = Vec::from_iter(input_groups.next().unwrap().lines().map(|l| l.to_string()));
+    return (possible_patterns, designs);
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let (possible_patterns, designs) = parse(contents);
+    let min_len = possible_patterns.iter().min_by(|a, b| a.len().cmp(&b.len())).unwrap().len();
+    let max_len = possible_patterns.iter().max_by(|a, b| a.len().cmp(&b.len())).unwrap().len();
+    return designs.into_iter().filter_map(|line| num_combos(line, possible_patterns.clone(), min_len, max_len)).count() as i64;
+
+}
+
+pub fn part2(contents: &str) -> i64 {
+    let (possible_patterns, designs) = parse(contents);
+    let min_len = possible_patterns.iter().min_by(|a, b| a.len().cmp(&b.len())).unwrap().len();
+    let max_len = possible_patterns.iter().max_by(|a, b| a.len().cmp(&b.len())).unwrap().len();
+    return designs.into_iter().filter_map(|line| num_combos(line, possible_patterns.clone(), min_len, max_len)).sum::<i64>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 6);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 16);
+    }
+}
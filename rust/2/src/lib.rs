@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use aoc_common::Variant;
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 2, name: "dp", solve: part2 },
+    Variant { part: 2, name: "remove_each", solve: part2_remove_each },
+    Variant { part: 2, name: "first_violation", solve: part2_first_violation },
+];
+
+/// What makes a report safe: every step between the levels kept is between
+/// `min_step` and `max_step` in the same direction, after removing at most
+/// `removable` levels.
+#[derive(Clone, Copy, Debug)]
+pub struct Rules {
+    pub min_step: i64,
+    pub max_step: i64,
+    pub removable: usize,
+}
+
+const PART1: Rules = Rules { min_step: 1, max_step: 3, removable: 0 };
+const PART2: Rules = Rules { min_step: 1, max_step: 3, removable: 1 };
+
+fn levels(line: &str) -> Vec<i64> {
+    return Vec::from_iter(line.split_whitespace().map(|n| n.parse::<i64>().unwrap()));
+}
+
+pub fn parse(contents: &str) -> Vec<Vec<i64>> {
+    return Vec::from_iter(contents.lines().map(levels));
+}
+
+/// The fewest levels to remove from `nums` to make it safe, or None if that
+/// takes more than `rules.removable`. `removed[i]` is the fewest removals
+/// before level `i` with `i` kept, and since at most `removable` levels can
+/// be skipped between kept ones only that many predecessors are looked at,
+/// so each report takes O(n * removable).
+pub fn fix(nums: &[i64], rules: &Rules) -> Option<Vec<usize>> {
+    let n = nums.len();
+    if n == 0 {
+        return Some(Vec::new());
+    }
+
+    let mut best: Option<Vec<usize>> = None;
+    for sign in [1, -1] {
+        let mut removed: Vec<usize> = Vec::from_iter(0..n);
+        let mut prev: Vec<Option<usize>> = vec![None; n];
+        for i in 0..n {
+            for p in i.saturating_sub(rules.removable + 1)..i {
+                let step = (nums[i] - nums[p]) * sign;
+                if (rules.min_step..=rules.max_step).contains(&step) && removed[p] + i - p - 1 < removed[i] {
+                    removed[i] = removed[p] + i - p - 1;
+                    prev[i] = Some(p);
+                }
+            }
+        }
+
+        // Everything after the last level kept is removed too
+        let last = (0..n).min_by_key(|i| removed[*i] + n - 1 - i).unwrap();
+        let mut kept = vec![false; n];
+        let mut at = Some(last);
+        while let Some(i) = at {
+            kept[i] = true;
+            at = prev[i];
+        }
+
+        let fixed: Vec<usize> = Vec::from_iter((0..n).filter(|i| !kept[*i]));
+        if fixed.len() <= rules.removable && best.as_ref().is_none_or(|b| fixed.len() < b.len()) {
+            best = Some(fixed);
+        }
+    }
+
+    return best;
+}
+
+pub fn count_safe(lines: impl Iterator<Item = impl AsRef<str>>, rules: &Rules) -> i64 {
+    return lines.filter(|line| fix(&levels(line.as_ref()), rules).is_some()).count() as i64;
+}
+
+fn part1_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    return count_safe(lines, &PART1);
+}
+
+fn part2_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    return count_safe(lines, &PART2);
+}
+
+pub fn part1(contents: &str) -> i64 {
+    return part1_lines(contents.lines());
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return part2_lines(contents.lines());
+}
+
+fn part2_remove_each(contents: &str) -> i64 {
+    return parse(contents).into_iter().filter_map(|nums| {
+        for rem_ix in 0..nums.len() {
+            let rem_nums: Vec<i64> = Vec::from_iter(nums.iter().enumerate().filter_map(|(ix, n)| {
+                if ix == rem_ix {
+                    None
+                } else {
+                    Some(*n)
+                }
+            }));
+            let diff_set: HashSet<i64> = HashSet::from_iter(rem_nums.windows(2).map(|ns| ns[1] - ns[0]));
+    
+            if diff_set.intersection(&HashSet::from([1, 2, 3])).count() == diff_set.len() || diff_set.intersection(&HashSet::from([-1, -2, -3])).count() == diff_set.len() {
+                return Some(nums);
+            }
+        }
+
+        None
+    }).count() as i64;
+}
+
+// Index of the first level whose step to the next isn't allowed in the direction of sign
+fn first_violation(nums: &[i64], sign: i64, rules: &Rules) -> Option<usize> {
+    return nums.windows(2).position(|ns| !(rules.min_step..=rules.max_step).contains(&((ns[1] - ns[0]) * sign)));
+}
+
+// Any fix has to remove one of the two levels in the first violating pair
+fn part2_first_violation(contents: &str) -> i64 {
+    return parse(contents).iter().filter(|nums| {
+        [1, -1].iter().any(|sign| {
+            match first_violation(nums, *sign, &PART2) {
+                None => true,
+                Some(ix) => [ix, ix + 1].iter().any(|rem_ix| {
+                    let mut rem_nums = nums.to_vec();
+                    rem_nums.remove(*rem_ix);
+                    first_violation(&rem_nums, *sign, &PART2).is_none()
+                }),
+            }
+        })
+    }).count() as i64;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Class {
+    Increasing,
+    Decreasing,
+    // The levels removed to make it safe
+    Fixable(Vec<usize>),
+    // The first pair that breaks the rules, going whichever way the report
+    // keeps to for longer
+    Unsafe(usize),
+}
+
+pub fn classify(nums: &[i64], rules: &Rules) -> Class {
+    let increasing = first_violation(nums, 1, rules);
+    let decreasing = first_violation(nums, -1, rules);
+    return match (increasing, decreasing, fix(nums, rules)) {
+        (None, _, _) => Class::Increasing,
+        (_, None, _) => Class::Decreasing,
+        (_, _, Some(removed)) => Class::Fixable(removed),
+        (Some(i), Some(d), None) => Class::Unsafe(i.max(d)),
+    };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Report {
+    None,
+    Table,
+    Csv,
+}
+
+impl FromStr for Report {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Report, String> {
+        match s {
+            "none" => Ok(Report::None),
+            "table" => Ok(Report::Table),
+            "csv" => Ok(Report::Csv),
+            _ => Err(format!("Unknown report {}", s)),
+        }
+    }
+}
+
+// One row per report: its number, class, the indices behind the class and its levels
+pub fn report_rows(lines: impl Iterator<Item = impl AsRef<str>>, rules: &Rules) -> Vec<[String; 4]> {
+    return Vec::from_iter(lines.enumerate().map(|(ix, line)| {
+        let nums = levels(line.as_ref());
+        let (class, indices) = match classify(&nums, rules) {
+            Class::Increasing => ("safe increasing", "".to_string()),
+            Class::Decreasing => ("safe decreasing", "".to_string()),
+            Class::Fixable(removed) => ("fixable", removed.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(" ")),
+            Class::Unsafe(pair) => ("unsafe", pair.to_string()),
+        };
+        [(ix + 1).to_string(), class.to_string(), indices, line.as_ref().to_string()]
+    }));
+}
+
+pub fn print_report(rows: &[[String; 4]], report: Report) {
+    let header = ["report", "class", "index", "levels"];
+    match report {
+        Report::None => {}
+        Report::Table => {
+            println!();
+            for row in [header.map(|h| h.to_string())].iter().chain(rows) {
+                println!("{:<8}{:<18}{:<8}{}", row[0], row[1], row[2], row[3]);
+            }
+        }
+        Report::Csv => {
+            println!("\n{}", header.join(","));
+            for row in rows {
+                println!("{}", row.join(","));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 2);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 4);
+    }
+
+    #[test]
+    fn variants_test() {
+        let contents = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n5 1 2 3 4\n1 2 3 4 9".to_string();
+        assert_eq!(part2(&contents), 6);
+        assert!(aoc_common::agree(&aoc_common::time_variants(&contents, VARIANTS)));
+    }
+
+    #[test]
+    fn fix_test() {
+        assert_eq!(fix(&[1, 3, 2, 4, 5], &PART2), Some(vec![2]));
+        assert_eq!(fix(&[7, 6, 4, 2, 1], &PART1), Some(vec![]));
+        assert_eq!(fix(&[1, 2, 7, 8, 9], &PART2), None);
+        assert_eq!(fix(&[1, 2, 7, 8, 9], &Rules { removable: 2, ..PART2 }), Some(vec![0, 1]));
+        assert_eq!(fix(&[1, 2, 7, 8, 9], &Rules { max_step: 5, ..PART1 }), Some(vec![]));
+        assert_eq!(fix(&[9, 7, 6, 2, 1], &PART2), None);
+    }
+
+    #[test]
+    fn classify_test() {
+        assert_eq!(classify(&[1, 3, 6, 7, 9], &PART2), Class::Increasing);
+        assert_eq!(classify(&[7, 6, 4, 2, 1], &PART2), Class::Decreasing);
+        assert_eq!(classify(&[8, 6, 4, 4, 1], &PART2), Class::Fixable(vec![3]));
+        assert_eq!(classify(&[1, 2, 7, 8, 9], &PART2), Class::Unsafe(1));
+        assert_eq!(classify(&[9, 7, 6, 2, 1], &PART1), Class::Unsafe(2));
+        // Flat steps are allowed both ways, so increasing breaks at 2 and decreasing at 1
+        assert_eq!(classify(&[5, 5, 6, 4], &Rules { min_step: 0, ..PART1 }), Class::Unsafe(2));
+        assert_eq!(report_rows("1 3 2 4 5".lines(), &PART2)[0], ["1", "fixable", "2", "1 3 2 4 5"].map(|s| s.to_string()));
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::{HashSet, HashMap, VecDeque};
+use aoc_common::Assumption;
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption { name: "single corridor", check: single_corridor },
+];
+
+// count_cheats treats the shortest path as the whole track, so the track
+// can't branch: S and E are its two ends and every other cell continues it
+fn single_corridor(contents: &str) -> Result<(), String> {
+    let mut available: HashSet<(i64, i64)> = HashSet::new();
+    let mut ends: Vec<(i64, i64)> = Vec::new();
+    for (y, line) in contents.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let pos = (x as i64, y as i64);
+            match c {
+                'S' | 'E' => {ends.push(pos); available.insert(pos);},
+                '.' => {available.insert(pos);},
+                _ => (),
+            }
+        }
+    }
+
+    if ends.len() != 2 {
+        return Err(format!("expected one S and one E, found {} endpoints", ends.len()));
+    }
+
+    for pos in &available {
+        let neighbors = [(1, 0), (0, 1), (-1, 0), (0, -1)].iter()
+            .filter(|(dx, dy)| available.contains(&(pos.0 + dx, pos.1 + dy)))
+            .count();
+        if ends.contains(pos) && neighbors != 1 {
+            return Err(format!("{:?} has {} open neighbors, expected 1", pos, neighbors));
+        }
+        if neighbors > 2 {
+            return Err(format!("{:?} has {} open neighbors, expected at most 2", pos, neighbors));
+        }
+    }
+
+    return Ok(());
+}
+
+fn min_path(start: (i64, i64), end: (i64, i64), available: &HashSet<(i64, i64)>) -> Option<Vec<(i64, i64)>> {
+    let mut checking: VecDeque<((i64, i64), Vec<(i64, i64)>)> = VecDeque::from([(
+        start,
+        Vec::from([start]),
+    )]);
+
+    while let Some((pos, path)) = checking.pop_front() {
+        if pos == end {
+            return Some(path);
+        }
+
+
+        for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+            let next_pos: (i64, i64) = (pos.0 + dx, pos.1 + dy);
+            if path.contains(&next_pos) || !available.contains(&next_pos) {
+                continue;
+            }
+            let mut next_path = path.clone();
+            next_path.push(next_pos);
+            checking.push_back((next_pos, next_path));
+        }
+    }
+
+    return None;
+}
+
+fn count_cheats(min_path: Vec<(i64, i64)>, available: &HashSet<(i64, i64)>, cheat_len: i64) -> HashMap<i64, i64> {
+    let mut cheats: HashMap<i64, i64> = HashMap::new();
+    let path_ixs: HashMap<(i64, i64), usize> = HashMap::from_iter(
+        min_path.iter().enumerate().map(|(ix, pos)| (*pos, ix))
+    );
+
+    for (start_ix, start_pos) in min_path.iter().enumerate() {
+        for dx in -cheat_len..=cheat_len {
+            let y_lim = cheat_len - dx.abs();
+            for dy in -y_lim..=y_lim {
+                let end_pos = (start_pos.0 + dx, start_pos.1 + dy);
+                if !available.contains(&end_pos) || *path_ixs.get(&end_pos).unwrap_or(&0) <= start_ix {
+                    continue;
+                }
+                *cheats.entry((*path_ixs.get(&end_pos).unwrap() as i64) - (start_ix as i64) - dx.abs() - dy.abs()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    return cheats;
+}
+
+/// The start, the end and every track cell.
+pub type Track = ((i64, i64), (i64, i64), HashSet<(i64, i64)>);
+
+pub fn parse(contents: &str) -> Track {
+    let mut start_pos: Option<(i64, i64)> = None;
+    let mut end_pos: Option<(i64, i64)> = None;
+    let mut available: HashSet<(i64, i64)> = HashSet::new();
+    for (y, line) in contents.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            let pos = (x as i64, y as i64);
+            match c {
+                'S' => {start_pos = Some(pos); available.insert(pos);},
+                'E' => {end_pos = Some(pos); available.insert(pos);},
+                '.' => {available.insert(pos);},
+                '#' => (),
+                _ => panic!("Unknown map character: {}", c)
+            }
+        }
+    }
+
+    return (start_pos.unwrap(), end_pos.unwrap(), available);
+}
+
+pub fn part1(contents: &str, min_saved: i64) -> i64 {
+    let (start_pos, end_pos, available) = parse(contents);
+
+    return count_cheats(min_path(start_pos, end_pos, &available).unwrap(), &available, 2).iter().filter_map(|(saved, amt)| {
+        if *saved >= min_saved {
+            Some(amt)
+        } else {
+            None
+        }
+    }).sum::<i64>();
+}
+
+pub fn part2(contents: &str, min_saved: i64) -> i64 {
+    let (start_pos, end_pos, available) = parse(contents);
+
+    return count_cheats(min_path(start_pos, end_pos, &available).unwrap(), &available, 20).iter().filter_map(|(saved, amt)| {
+        if *saved >= min_saved {
+            Some(amt)
+        } else {
+            None
+        }
+    }).sum::<i64>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents, 1), 44);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents, 50), 285);
+    }
+
+    #[test]
+    fn single_corridor_test() {
+        assert!(single_corridor("#####\n#S.E#\n#####").is_ok());
+        assert!(single_corridor("#####\n#S.E#\n#..##\n#####").is_err());
+    }
+}
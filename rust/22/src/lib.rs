@@ -0,0 +1,92 @@
+use std::collections::{HashSet, HashMap, VecDeque};
+use itertools::Itertools;
+
+fn mix_prune(secret: i64, val: i64) -> i64 {
+    (secret ^ val) & 0xffffff
+}
+
+fn secret(line: &str) -> i64 {
+    return line.parse::<i64>().unwrap();
+}
+
+pub fn part1_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    return lines.map(|line| {
+        let mut n = secret(line.as_ref());
+        for _ in 0..2000 {
+            n = mix_prune(n, n << 6);
+            n = mix_prune(n, n >> 5);
+            n = mix_prune(n, n << 11);
+        }
+
+        n
+    }).sum::<i64>();
+}
+
+pub fn part2_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    let mut bananas_gained: HashMap<(i64, i64, i64, i64), i64> = HashMap::new();
+    for line in lines {
+        let mut n = secret(line.as_ref());
+        let mut p_b = n % 10;
+        let mut last_4: VecDeque<i64> = VecDeque::new();
+        let mut used: HashSet<(i64, i64, i64, i64)> = HashSet::new();
+
+        for _ in 0..2000 {
+            n = mix_prune(n, n << 6);
+            n = mix_prune(n, n >> 5);
+            n = mix_prune(n, n << 11);
+
+            let b = n % 10;
+            let diff = b - p_b;
+            p_b = b;
+
+            last_4.push_front(diff);
+            last_4.truncate(4);
+            if last_4.len() == 4 {
+                let key = last_4.iter().map(|v| *v).collect_tuple().unwrap();
+                if used.contains(&key) {
+                    continue;
+                }
+
+                *bananas_gained.entry(key).or_insert(0) += b;
+                used.insert(key);
+            }
+        }
+    }
+
+    return *bananas_gained.values().max().unwrap();
+}
+
+/// Each buyer's initial secret number.
+pub fn parse(contents: &str) -> Vec<i64> {
+    return Vec::from_iter(contents.lines().map(secret));
+}
+
+pub fn part1(contents: &str) -> i64 {
+    return part1_lines(contents.lines());
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return part2_lines(contents.lines());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example1.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 37327623);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example2.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 23);
+    }
+}
@@ -0,0 +1,95 @@
+use itertools::Itertools;
+use std::iter::zip;
+use aoc_common::Assumption;
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption { name: "5x7 schematics", check: schematic_shape },
+    Assumption { name: "schematics are keys or locks", check: keys_or_locks },
+];
+
+fn schematic_shape(contents: &str) -> Result<(), String> {
+    for (ix, grouping) in contents.split("\n\n").enumerate() {
+        let rows: Vec<&str> = grouping.lines().collect();
+        if rows.len() != 7 || rows.iter().any(|row| row.chars().count() != 5) {
+            let width = rows.iter().map(|row| row.chars().count()).max().unwrap_or(0);
+            return Err(format!("schematic {} is {} wide and {} tall", ix, width, rows.len()));
+        }
+    }
+
+    return Ok(());
+}
+
+fn keys_or_locks(contents: &str) -> Result<(), String> {
+    for (ix, grouping) in contents.split("\n\n").enumerate() {
+        let rows: Vec<&str> = grouping.lines().collect();
+        let filled = |row: Option<&&str>| row.is_some_and(|r| r.chars().all(|c| c == '#'));
+        if filled(rows.first()) == filled(rows.last()) {
+            return Err(format!("schematic {} is neither a key nor a lock", ix));
+        }
+    }
+
+    return Ok(());
+}
+
+/// The column heights of every key and every lock.
+pub fn parse(contents: &str) -> (Vec<Vec<i64>>, Vec<Vec<i64>>) {
+    let mut keys: Vec<Vec<i64>> = Vec::new();
+    let mut locks: Vec<Vec<i64>> = Vec::new();
+    
+    for grouping in contents.split("\n\n") {
+        let mut new_item: Vec<i64> = vec![0; 5];
+        let mut key_lockb: bool = true;
+        for line in grouping.lines() {
+            for (ix, c) in line.chars().enumerate() {
+                if c == '#' {
+                    new_item[ix] += 1;
+                    key_lockb = true;
+                } else {
+                    key_lockb = false;
+                }
+            }
+        }
+        
+        if key_lockb {
+            keys.push(new_item);
+        } else {
+            locks.push(new_item);
+        }
+    }
+
+    return (keys, locks);
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let (keys, locks) = parse(contents);
+    let height: i64 = 7;
+    return keys.iter().cartesian_product(locks.iter()).filter(|(k, l)| {
+        zip(k.into_iter(), l.into_iter()).all(|(kh, lh)| kh + lh <= height)
+    }).count() as i64;
+}
+
+pub fn part2(contents: &str) -> String {
+    return "Christmas has been saved!".to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 3);
+    }
+
+    #[test]
+    fn schematic_test() {
+        let lock = "#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....";
+        assert!(schematic_shape(lock).is_ok() && keys_or_locks(lock).is_ok());
+        assert!(schematic_shape("####\n.###\n....").is_err());
+        assert!(keys_or_locks("#####\n.####\n.####\n.####\n.#.#.\n.#...\n#####").is_err());
+    }
+}
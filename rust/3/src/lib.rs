@@ -0,0 +1,568 @@
+use std::io::{self, Read};
+use std::ops::{Range, RangeInclusive};
+use std::str::{self, FromStr};
+use regex::Regex;
+use aoc_common::Variant;
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 1, name: "interpreter", solve: part1 },
+    Variant { part: 1, name: "regex", solve: part1_regex },
+    Variant { part: 2, name: "interpreter", solve: part2 },
+    Variant { part: 2, name: "regex", solve: part2_regex },
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Token<'a> {
+    Name(&'a str),
+    Number(&'a str),
+    Open,
+    Close,
+    Comma,
+    Other,
+}
+
+/// A token and the bytes of the input it came from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spanned<'a> {
+    pub token: Token<'a>,
+    pub span: Range<usize>,
+}
+
+/// Splits memory into runs of letters (instruction names can end one, as in
+/// `xmul`), numbers with an optional sign, punctuation, and anything else a
+/// byte at a time.
+pub fn lex(input: &str) -> Vec<Spanned<'_>> {
+    let bytes = input.as_bytes();
+    let mut tokens: Vec<Spanned> = Vec::new();
+    let mut ix = 0;
+    while ix < bytes.len() {
+        let start = ix;
+        let is_digit = |i: usize| i < bytes.len() && bytes[i].is_ascii_digit();
+        let token = match bytes[ix] {
+            b'(' => Token::Open,
+            b')' => Token::Close,
+            b',' => Token::Comma,
+            b if b.is_ascii_alphabetic() || b == b'\'' => {
+                while ix + 1 < bytes.len() && (bytes[ix + 1].is_ascii_alphabetic() || bytes[ix + 1] == b'\'') {
+                    ix += 1;
+                }
+                Token::Name(&input[start..ix + 1])
+            }
+            b if b.is_ascii_digit() || (b == b'-' && is_digit(ix + 1)) => {
+                while is_digit(ix + 1) {
+                    ix += 1;
+                }
+                Token::Number(&input[start..ix + 1])
+            }
+            _ => {
+                // Keep spans on character boundaries
+                while ix + 1 < bytes.len() && !input.is_char_boundary(ix + 1) {
+                    ix += 1;
+                }
+                Token::Other
+            }
+        };
+        ix += 1;
+        tokens.push(Spanned { token, span: start..ix });
+    }
+
+    return tokens;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Kind {
+    // Evaluates its arguments, adding the result to the total while enabled
+    Value(fn(&[i64]) -> i64),
+    // Switches adding results on or off
+    Enable(bool),
+}
+
+#[derive(Clone, Debug)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub args: RangeInclusive<usize>,
+    pub kind: Kind,
+}
+
+fn product(args: &[i64]) -> i64 {
+    return args.iter().product::<i64>();
+}
+
+fn sum(args: &[i64]) -> i64 {
+    return args.iter().sum::<i64>();
+}
+
+fn difference(args: &[i64]) -> i64 {
+    return args[0] - args[1..].iter().sum::<i64>();
+}
+
+pub const MUL: Instruction = Instruction { name: "mul", args: 2..=2, kind: Kind::Value(product) };
+pub const ADD: Instruction = Instruction { name: "add", args: 2..=2, kind: Kind::Value(sum) };
+pub const SUB: Instruction = Instruction { name: "sub", args: 2..=2, kind: Kind::Value(difference) };
+pub const DO: Instruction = Instruction { name: "do", args: 0..=0, kind: Kind::Enable(true) };
+pub const DONT: Instruction = Instruction { name: "don't", args: 0..=0, kind: Kind::Enable(false) };
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Arg {
+    Number(i64),
+    Call(Call),
+}
+
+/// A well-formed instruction found in memory, by its index in the table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    pub instruction: usize,
+    pub args: Vec<Arg>,
+    pub span: Range<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct State {
+    pub enabled: bool,
+    pub total: i64,
+}
+
+impl Default for State {
+    fn default() -> State {
+        return State { enabled: true, total: 0 };
+    }
+}
+
+/// Which instructions are recognised and how their arguments may look.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub instructions: Vec<Instruction>,
+    // Longest number an argument may have. Unlimited by default, like the
+    // reference regex variants; the puzzle text allows at most 3 digits,
+    // which `with_digit_limit` enforces
+    pub max_digits: usize,
+    // Whether a value instruction can be another's argument, as in `mul(add(1,2),3)`
+    pub nested: bool,
+}
+
+impl Config {
+    pub fn new(instructions: &[Instruction]) -> Config {
+        return Config { instructions: instructions.to_vec(), max_digits: usize::MAX, nested: false };
+    }
+
+    /// The same instructions, only accepting arguments of up to `max_digits` digits.
+    pub fn with_digit_limit(self, max_digits: usize) -> Config {
+        return Config { max_digits, ..self };
+    }
+
+    // The instruction whose name ends the name token, preferring the longest
+    fn named(&self, name: &str) -> Option<usize> {
+        return (0..self.instructions.len())
+            .filter(|ix| name.ends_with(self.instructions[*ix].name))
+            .max_by_key(|ix| self.instructions[*ix].name.len());
+    }
+
+    fn arg(&self, tokens: &[Spanned], ix: usize) -> Option<(Arg, usize)> {
+        match tokens.get(ix)?.token {
+            Token::Number(n) if n.trim_start_matches('-').len() <= self.max_digits => {
+                return Some((Arg::Number(n.parse::<i64>().ok()?), ix + 1));
+            }
+            Token::Name(_) if self.nested => {
+                let (call, next) = self.call(tokens, ix)?;
+                return match self.instructions[call.instruction].kind {
+                    Kind::Value(_) => Some((Arg::Call(call), next)),
+                    Kind::Enable(_) => None,
+                };
+            }
+            _ => return None,
+        }
+    }
+
+    /// Parses the call named by the token at `ix`, returning it with the
+    /// index of the token after it.
+    pub fn call(&self, tokens: &[Spanned], ix: usize) -> Option<(Call, usize)> {
+        let Token::Name(name) = tokens[ix].token else {
+            return None;
+        };
+        let instruction = self.named(name)?;
+        let spec = &self.instructions[instruction];
+        if tokens.get(ix + 1)?.token != Token::Open {
+            return None;
+        }
+
+        let mut args: Vec<Arg> = Vec::new();
+        let mut next = ix + 2;
+        while tokens.get(next)?.token != Token::Close {
+            if !args.is_empty() {
+                if tokens[next].token != Token::Comma {
+                    return None;
+                }
+                next += 1;
+            }
+            let (arg, after) = self.arg(tokens, next)?;
+            args.push(arg);
+            next = after;
+        }
+        if !spec.args.contains(&args.len()) {
+            return None;
+        }
+
+        let span = tokens[ix].span.end - spec.name.len()..tokens[next].span.end;
+        return Some((Call { instruction, args, span }, next + 1));
+    }
+
+    /// Every well-formed call in `input`, scanning on from the end of each
+    /// one found or the next token after anything that isn't one.
+    pub fn calls(&self, input: &str) -> Vec<Call> {
+        let tokens = lex(input);
+        let mut calls: Vec<Call> = Vec::new();
+        let mut ix = 0;
+        while ix < tokens.len() {
+            match self.call(&tokens, ix) {
+                Some((call, next)) => {
+                    calls.push(call);
+                    ix = next;
+                }
+                None => ix += 1,
+            }
+        }
+
+        return calls;
+    }
+
+    pub fn eval(&self, call: &Call) -> Option<i64> {
+        let Kind::Value(f) = self.instructions[call.instruction].kind else {
+            return None;
+        };
+        let args: Vec<i64> = Vec::from_iter(call.args.iter().map(|arg| match arg {
+            Arg::Number(n) => *n,
+            Arg::Call(inner) => self.eval(inner).unwrap(),
+        }));
+        return Some(f(&args));
+    }
+
+    pub fn exec(&self, state: &mut State, call: &Call) {
+        match self.instructions[call.instruction].kind {
+            Kind::Enable(enabled) => state.enabled = enabled,
+            Kind::Value(_) if state.enabled => state.total += self.eval(call).unwrap(),
+            Kind::Value(_) => {}
+        }
+    }
+
+    pub fn run(&self, state: &mut State, input: &str) {
+        for call in self.calls(input) {
+            self.exec(state, &call);
+        }
+    }
+}
+
+// Calls much longer than this (only possible with nesting) are given up on
+// rather than held back indefinitely
+const MAX_CARRY: usize = 4096;
+
+pub const CHUNK_SIZE: usize = 1 << 16;
+
+// Whether more input could still turn the tokens from `ix` on into a call:
+// calls are only ever names, numbers and punctuation, and a trailing `-`
+// might start a number
+fn could_continue(text: &str, tokens: &[Spanned], ix: usize) -> bool {
+    return tokens[ix..].iter().enumerate().all(|(i, t)| {
+        t.token != Token::Other || (ix + i == tokens.len() - 1 && &text[t.span.clone()] == "-")
+    });
+}
+
+/// Runs `config` over `reader` a chunk at a time, holding back anything at
+/// the end of a chunk that the next one might complete, so memory use is
+/// bounded by the chunk size however large the input. `progress` gets the
+/// bytes read so far and the state after each chunk.
+pub fn scan(mut reader: impl Read, config: &Config, chunk_size: usize, mut progress: impl FnMut(u64, &State)) -> io::Result<State> {
+    let longest_name = config.instructions.iter().map(|i| i.name.len()).max().unwrap_or(0);
+    let mut state = State::default();
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut chunk = vec![0; chunk_size];
+    let mut read: u64 = 0;
+    loop {
+        let n = reader.read(&mut chunk)?;
+        read += n as u64;
+        // Instructions are ASCII, so anything else can be blanked out, which
+        // also means memory dumps needn't be valid UTF-8
+        buffer.extend(chunk[..n].iter().map(|b| if b.is_ascii() { *b } else { 0 }));
+        let text = str::from_utf8(&buffer).unwrap();
+        let tokens = lex(text);
+
+        let mut keep = buffer.len();
+        let mut ix = 0;
+        while ix < tokens.len() {
+            match config.call(&tokens, ix) {
+                Some((call, next)) => {
+                    config.exec(&mut state, &call);
+                    ix = next;
+                }
+                None if n > 0 && buffer.len() - tokens[ix].span.start <= MAX_CARRY && could_continue(text, &tokens, ix) => {
+                    // Only the end of a name can be an instruction's
+                    keep = match tokens[ix].token {
+                        Token::Name(_) => tokens[ix].span.start.max(tokens[ix].span.end.saturating_sub(longest_name)),
+                        _ => tokens[ix].span.start,
+                    };
+                    break;
+                }
+                None => ix += 1,
+            }
+        }
+
+        buffer.drain(..keep);
+        progress(read, &state);
+        if n == 0 {
+            return Ok(state);
+        }
+    }
+}
+
+fn total(reader: impl Read, config: &Config) -> i64 {
+    return scan(reader, config, CHUNK_SIZE, |_, _| {}).unwrap().total;
+}
+
+/// Every well-formed call in memory, using part 2's instructions.
+pub fn parse(contents: &str) -> Vec<Call> {
+    return Config::new(&[MUL, DO, DONT]).calls(contents);
+}
+
+pub fn part1(contents: &str) -> i64 {
+    return total(contents.as_bytes(), &Config::new(&[MUL]));
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return total(contents.as_bytes(), &Config::new(&[MUL, DO, DONT]));
+}
+
+fn part1_regex(contents: &str) -> i64 {
+    let mul_re = Regex::new(r"mul\((-?\d+),(-?\d+)\)").unwrap();
+    return mul_re.captures_iter(contents).map(|caps| {
+        caps.get(1).unwrap().as_str().parse::<i64>().unwrap() * caps.get(2).unwrap().as_str().parse::<i64>().unwrap()
+    }).sum::<i64>();
+}
+
+fn part2_regex(contents: &str) -> i64 {
+    let mul_re = Regex::new(r"do\(\)|don't\(\)|mul\((-?\d+),(-?\d+)\)").unwrap();
+    return mul_re.captures_iter(contents).fold((0, true), |(sum, enabled), caps| {
+        match caps.get(0).unwrap().as_str() {
+            "do()" => (sum, true),
+            "don't()" => (sum, false),
+            _ => (sum + ((enabled as i64) * caps.get(1).unwrap().as_str().parse::<i64>().unwrap() * caps.get(2).unwrap().as_str().parse::<i64>().unwrap()), enabled)
+        }
+    }).0;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mark {
+    Enabled,
+    Disabled,
+    Switch,
+    // Named like an instruction and opening a bracket, but rejected
+    NearMiss,
+}
+
+// Near misses are cut off after this many bytes
+const MAX_NEAR_MISS: usize = 16;
+
+// How far what follows the name at `ix` looks like an attempted call: up to
+// the first closing bracket, stopping short of the next name
+fn near_miss(text: &str, tokens: &[Spanned], ix: usize, config: &Config) -> Option<Range<usize>> {
+    let Token::Name(name) = tokens[ix].token else {
+        return None;
+    };
+    let start = tokens[ix].span.end - config.instructions[config.named(name)?].name.len();
+    let open = tokens.get(ix + 1)?;
+    if !["(", "[", "{", "<"].contains(&&text[open.span.clone()]) {
+        return None;
+    }
+
+    let mut end = open.span.end;
+    for t in &tokens[ix + 2..] {
+        if matches!(t.token, Token::Name(_)) || t.span.end - start > MAX_NEAR_MISS {
+            break;
+        }
+        end = t.span.end;
+        if [")", "]", "}", ">"].contains(&&text[t.span.clone()]) {
+            break;
+        }
+    }
+    return Some(start..end);
+}
+
+/// The spans of `contents` worth highlighting, in order: each call, marked
+/// by whether the instructions before it left adding results enabled, and
+/// each near miss.
+pub fn marks(contents: &str, config: &Config) -> Vec<(Range<usize>, Mark)> {
+    let tokens = lex(contents);
+    let mut marks: Vec<(Range<usize>, Mark)> = Vec::new();
+    let mut state = State::default();
+    let mut ix = 0;
+    while ix < tokens.len() {
+        match config.call(&tokens, ix) {
+            Some((call, next)) => {
+                let mark = match config.instructions[call.instruction].kind {
+                    Kind::Enable(_) => Mark::Switch,
+                    Kind::Value(_) if state.enabled => Mark::Enabled,
+                    Kind::Value(_) => Mark::Disabled,
+                };
+                config.exec(&mut state, &call);
+                marks.push((call.span, mark));
+                ix = next;
+            }
+            None => {
+                if let Some(span) = near_miss(contents, &tokens, ix, config) {
+                    marks.push((span, Mark::NearMiss));
+                }
+                ix += 1;
+            }
+        }
+    }
+
+    return marks;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Annotate {
+    None,
+    Ansi,
+    Html,
+}
+
+impl FromStr for Annotate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Annotate, String> {
+        match s {
+            "none" => Ok(Annotate::None),
+            "ansi" => Ok(Annotate::Ansi),
+            "html" => Ok(Annotate::Html),
+            _ => Err(format!("Unknown annotation style {}", s)),
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    return text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+}
+
+/// `contents` with its marks highlighted, followed by a list of the near misses.
+pub fn annotate(contents: &str, marks: &[(Range<usize>, Mark)], style: Annotate) -> String {
+    let html = style == Annotate::Html;
+    let escape = |text: &str| if html { escape_html(text) } else { text.to_string() };
+    let mut out = String::new();
+    if html {
+        out.push_str("<style>.enabled { color: green; font-weight: bold } .disabled { color: red; opacity: 0.6 } ");
+        out.push_str(".switch { color: teal } .near-miss { color: orange; text-decoration: underline wavy }</style>\n<pre>");
+    }
+
+    let mut at = 0;
+    for (span, mark) in marks {
+        out.push_str(&escape(&contents[at..span.start]));
+        let (ansi, class) = match mark {
+            Mark::Enabled => ("\x1b[1;32m", "enabled"),
+            Mark::Disabled => ("\x1b[2;31m", "disabled"),
+            Mark::Switch => ("\x1b[36m", "switch"),
+            Mark::NearMiss => ("\x1b[4;33m", "near-miss"),
+        };
+        if html {
+            out.push_str(&format!("<span class=\"{}\">{}</span>", class, escape(&contents[span.clone()])));
+        } else {
+            out.push_str(&format!("{}{}\x1b[0m", ansi, &contents[span.clone()]));
+        }
+        at = span.end;
+    }
+    out.push_str(&escape(&contents[at..]));
+
+    let near_misses = Vec::from_iter(marks.iter().filter(|(_, mark)| *mark == Mark::NearMiss));
+    if html {
+        out.push_str("</pre>\n<h3>Near misses</h3>\n<ul>\n");
+        for (span, _) in near_misses {
+            out.push_str(&format!("<li>{}: <code>{}</code></li>\n", span.start, escape(&contents[span.clone()])));
+        }
+        out.push_str("</ul>\n");
+    } else {
+        out.push_str("\nNear misses:\n");
+        for (span, _) in near_misses {
+            out.push_str(&format!("{:>8}: {}\n", span.start, &contents[span.clone()]));
+        }
+    }
+
+    return out;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        assert_eq!(part1("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))"), 161);
+    }
+
+    #[test]
+    fn p2_test() {
+        assert_eq!(part2("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))"), 48);
+    }
+
+    #[test]
+    fn lines_test() {
+        assert_eq!(part2("mul(2,4)don't()\nmul(5,5)\ndo()mul(8,5)"), 48);
+    }
+
+    #[test]
+    fn scan_test() {
+        let contents = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))mul(-2,3)é";
+        let config = Config::new(&[MUL, DO, DONT]);
+        for chunk_size in 1..12 {
+            assert_eq!(scan(contents.as_bytes(), &config, chunk_size, |_, _| {}).unwrap().total, 48 - 6);
+        }
+
+        let mut totals: Vec<i64> = Vec::new();
+        scan("mul(2,4)mul(3,3)".as_bytes(), &config, 8, |_, state| totals.push(state.total)).unwrap();
+        assert_eq!(totals, vec![8, 17, 17]);
+    }
+
+    #[test]
+    fn marks_test() {
+        let contents = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let marks = marks(contents, &Config::new(&[MUL, DO, DONT]));
+        assert_eq!(Vec::from_iter(marks.iter().map(|(span, mark)| (&contents[span.clone()], *mark))), vec![
+            ("mul(2,4)", Mark::Enabled),
+            ("mul[3,7]", Mark::NearMiss),
+            ("don't()", Mark::Switch),
+            ("mul(5,5)", Mark::Disabled),
+            ("mul(32,64]", Mark::NearMiss),
+            ("mul(11,8)", Mark::Disabled),
+            ("do()", Mark::Switch),
+            ("mul(8,5)", Mark::Enabled),
+        ]);
+        assert!(annotate("a<mul(1,2)", &marks[..0], Annotate::Html).contains("a&lt;mul"));
+    }
+
+    #[test]
+    fn lex_test() {
+        let tokens = lex("xmul(-2,4)é");
+        assert_eq!(tokens[0], Spanned { token: Token::Name("xmul"), span: 0..4 });
+        assert_eq!(tokens[2], Spanned { token: Token::Number("-2"), span: 5..7 });
+        assert_eq!(tokens.last().unwrap(), &Spanned { token: Token::Other, span: 10..12 });
+    }
+
+    #[test]
+    fn interpreter_test() {
+        let mut config = Config::new(&[MUL, ADD, SUB, DO, DONT]).with_digit_limit(3);
+        let calls = config.calls("xmul(2,4)add(1,mul(2,3))mul(1000,2)");
+        assert_eq!(Vec::from_iter(calls.iter().map(|call| call.span.clone())), vec![1..9, 15..23]);
+
+        config.nested = true;
+        let mut state = State::default();
+        config.run(&mut state, "add(1,mul(2,3))sub(10,3)don't()mul(5,5)do()mul(add(1,1),sub(4,1))");
+        assert_eq!(state, State { enabled: true, total: 7 + 7 + 6 });
+
+        config.instructions[2].args = 2..=3;
+        config.run(&mut state, "sub(10,3,2)mul(1234,1)");
+        assert_eq!(state.total, 20 + 5);
+        config.max_digits = 4;
+        config.run(&mut state, "mul(1234,1)");
+        assert_eq!(state.total, 25 + 1234);
+
+        // Without a limit, long arguments count just as they do for the regex
+        assert_eq!(part1("mul(1234,1)"), 1234);
+        assert_eq!(part2_regex("mul(12345,2)"), part2("mul(12345,2)"));
+    }
+}
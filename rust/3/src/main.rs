@@ -1,71 +1,60 @@
-use relative_path::RelativePath;
-use std::env;
-use std::fs;
 use std::time::Instant;
-use regex::Regex;
+use std::io::Read;
+use aoc_common::Source;
+use rust_2024_3::{ADD, Annotate, CHUNK_SIZE, Config, DO, DONT, Kind, MUL, SUB, State, VARIANTS, annotate, marks, scan};
 
-fn part1(contents: String) -> i64 {
-    let mul_re = Regex::new(r"mul\((-?\d+),(-?\d+)\)").unwrap();
-    return mul_re.captures_iter(&contents).map(|caps| {
-        caps.get(1).unwrap().as_str().parse::<i64>().unwrap() * caps.get(2).unwrap().as_str().parse::<i64>().unwrap()
-    }).sum::<i64>();
-}
-
-fn part2(contents: String) -> i64 {
-    let mul_re = Regex::new(r"do\(\)|don't\(\)|mul\((-?\d+),(-?\d+)\)").unwrap();
-    return mul_re.captures_iter(&contents).fold((0, true), |(sum, enabled), caps| {
-        match caps.get(0).unwrap().as_str() {
-            "do()" => (sum, true),
-            "don't()" => (sum, false),
-            _ => (sum + ((enabled as i64) * caps.get(1).unwrap().as_str().parse::<i64>().unwrap() * caps.get(2).unwrap().as_str().parse::<i64>().unwrap()), enabled)
-        }
-    }).0;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn p1_test() {
-        assert_eq!(part1("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))".to_string()), 161);
+fn main() {
+    let puzzle = aoc_common::puzzle!();
+    let source = puzzle.source();
+    if let Source::Loaded(contents) = &source {
+        puzzle.compare_variants(contents, VARIANTS);
     }
 
-    #[test]
-    fn p2_test() {
-        assert_eq!(part2("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))".to_string()), 48);
+    let max_args = puzzle.param("max_args", 2);
+    let mut instructions = vec![MUL, DO, DONT];
+    if puzzle.param("extended", false) {
+        instructions.extend([ADD, SUB]);
     }
-}
-
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let year = "2024".to_string();
-    let day = "3".to_string();
-
-    let root = env::current_dir().unwrap();
-    let path_str = if args.len() > 1 {
-        args[1].clone()
-    } else if root.ends_with(format!("{}", day)) {
-        format!("../../../Inputs/{}_{}.txt", year, day)
-    } else {
-        format!("/Inputs/{}_{}.txt", year, day)
+    for instruction in instructions.iter_mut().filter(|i| matches!(i.kind, Kind::Value(_))) {
+        instruction.args = 2..=max_args;
+    }
+    let mut part2_config = Config::new(&instructions);
+    // The puzzle's 3-digit limit only applies when asked for
+    part2_config.max_digits = puzzle.param("max_digits", usize::MAX);
+    part2_config.nested = puzzle.param("nested", false);
+    let part1_config = Config {
+        instructions: Vec::from_iter(instructions.iter().filter(|i| matches!(i.kind, Kind::Value(_))).cloned()),
+        ..part2_config.clone()
     };
 
-    let contents = fs::read_to_string(if args.len() > 1 {path_str} else {RelativePath::new(&path_str).to_path(&root).display().to_string()})
-        .expect("Should have been able to read the file");
+    // Memory dumps needn't have line breaks, so they're read in chunks
+    let chunk_size = puzzle.param("chunk_size", CHUNK_SIZE);
+    let show_progress = puzzle.param("progress", false);
+    let progress = |read: u64, state: &State| {
+        if show_progress {
+            eprint!("\r{} bytes read, total {}", read, state.total);
+        }
+    };
 
     let part1_timer = Instant::now();
     println!(
         "\nPart 1:\nSum of multiplications: {}\nRan in {:.5?}",
-        part1(contents.clone()),
+        scan(source.reader(), &part1_config, chunk_size, progress).unwrap().total,
         part1_timer.elapsed()
     );
 
     let part2_timer = Instant::now();
     println!(
         "\nPart 2:\nSum of enabled multiplications: {}\nRan in {:.5?}",
-        part2(contents.clone()),
+        scan(source.reader(), &part2_config, chunk_size, progress).unwrap().total,
         part2_timer.elapsed()
     );
+
+    let style = puzzle.param("annotate", Annotate::None);
+    if style != Annotate::None {
+        let mut bytes: Vec<u8> = Vec::new();
+        source.reader().read_to_end(&mut bytes).unwrap();
+        let contents = String::from_utf8_lossy(&bytes);
+        println!("\n{}", annotate(&contents, &marks(&contents, &part2_config), style));
+    }
 }
\ No newline at end of file
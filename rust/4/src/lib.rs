@@ -0,0 +1,336 @@
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use aho_corasick::AhoCorasick;
+use aoc_common::Variant;
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 1, name: "automaton", solve: part1 },
+    Variant { part: 1, name: "step", solve: part1_step },
+    Variant { part: 2, name: "stencil", solve: part2 },
+    Variant { part: 2, name: "centres", solve: part2_centres },
+];
+
+pub type Point = (i64, i64);
+
+pub const DIRECTIONS: [(&str, Point); 8] = [
+    ("E", (1, 0)),
+    ("SE", (1, 1)),
+    ("S", (0, 1)),
+    ("SW", (-1, 1)),
+    ("W", (-1, 0)),
+    ("NW", (-1, -1)),
+    ("N", (0, -1)),
+    ("NE", (1, -1)),
+];
+
+/// A word found in the grid, by its index in the word list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Match {
+    pub word: usize,
+    pub start: Point,
+    pub direction: Point,
+}
+
+/// Finds any of a list of words running in any of `directions`. Every line
+/// through the grid in each direction is fed once through an Aho-Corasick
+/// automaton over all the words, so overlapping matches are all found.
+pub struct WordSearch {
+    automaton: AhoCorasick,
+    longest: usize,
+    pub directions: Vec<Point>,
+    // Whether words can run off one edge and carry on from the opposite one
+    pub wrap: bool,
+}
+
+impl WordSearch {
+    pub fn new(words: &[&str]) -> WordSearch {
+        return WordSearch {
+            automaton: AhoCorasick::new(words).unwrap(),
+            longest: words.iter().map(|w| w.len()).max().unwrap_or(0),
+            directions: Vec::from_iter(DIRECTIONS.iter().map(|(_, d)| *d)),
+            wrap: false,
+        };
+    }
+
+    // Each line through the grid in `direction`, with how many of its cells
+    // a match can start from. Wrapped lines are cycles, so they're extended
+    // far enough for a word to run past where they began.
+    fn lines(&self, width: i64, height: i64, direction: Point) -> Vec<(Vec<Point>, usize)> {
+        let inside = |(x, y): Point| x >= 0 && y >= 0 && x < width && y < height;
+        let mut lines: Vec<(Vec<Point>, usize)> = Vec::new();
+        if !self.wrap {
+            for start in (0..height).cartesian_product(0..width).map(|(y, x)| (x, y)) {
+                if inside((start.0 - direction.0, start.1 - direction.1)) {
+                    continue;
+                }
+                let line = Vec::from_iter(std::iter::successors(Some(start), |p| Some((p.0 + direction.0, p.1 + direction.1))).take_while(|p| inside(*p)));
+                let len = line.len();
+                lines.push((line, len));
+            }
+            return lines;
+        }
+
+        let mut seen: HashSet<Point> = HashSet::new();
+        for start in (0..height).cartesian_product(0..width).map(|(y, x)| (x, y)) {
+            if seen.contains(&start) {
+                continue;
+            }
+            let mut cycle = vec![start];
+            loop {
+                let last = cycle[cycle.len() - 1];
+                let next = ((last.0 + direction.0).rem_euclid(width), (last.1 + direction.1).rem_euclid(height));
+                if next == start {
+                    break;
+                }
+                cycle.push(next);
+            }
+            seen.extend(cycle.iter());
+            let len = cycle.len();
+            lines.push((Vec::from_iter((0..len + self.longest.saturating_sub(1)).map(|ix| cycle[ix % len])), len));
+        }
+        return lines;
+    }
+
+    pub fn find(&self, grid: &[&[u8]]) -> Vec<Match> {
+        let (width, height) = (grid.iter().map(|row| row.len()).max().unwrap_or(0) as i64, grid.len() as i64);
+        let mut matches: Vec<Match> = Vec::new();
+        for direction in &self.directions {
+            for (line, starts) in self.lines(width, height, *direction) {
+                // Short rows end early, and no word runs through a cell past their end
+                let haystack = Vec::from_iter(line.iter().map(|(x, y)| grid[*y as usize].get(*x as usize).copied().unwrap_or(0)));
+                for m in self.automaton.find_overlapping_iter(&haystack) {
+                    if m.start() < starts {
+                        matches.push(Match { word: m.pattern().as_usize(), start: line[m.start()], direction: *direction });
+                    }
+                }
+            }
+        }
+
+        return matches;
+    }
+}
+
+pub fn parse(contents: &str) -> Vec<&[u8]> {
+    return Vec::from_iter(contents.lines().map(|line| line.as_bytes()));
+}
+
+/// A small grid of characters to match against every position of a larger
+/// one, where `.` matches anything.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stencil {
+    rows: Vec<Vec<u8>>,
+}
+
+impl Stencil {
+    /// Rows separated by newlines or `/`, e.g. `M.S/.A./M.S`. Short rows are
+    /// padded with `.` so every row is as wide as the widest.
+    pub fn parse(pattern: &str) -> Stencil {
+        let mut rows = Vec::from_iter(pattern.split(['\n', '/']).map(|row| row.as_bytes().to_vec()));
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, b'.');
+        }
+        return Stencil { rows };
+    }
+
+    fn width(&self) -> usize {
+        return self.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    }
+
+    // A quarter turn clockwise
+    fn rotate(&self) -> Stencil {
+        let (width, height) = (self.width(), self.rows.len());
+        return Stencil { rows: Vec::from_iter((0..width).map(|x| {
+            Vec::from_iter((0..height).rev().map(|y| self.rows[y][x]))
+        })) };
+    }
+
+    fn reflect(&self) -> Stencil {
+        return Stencil { rows: Vec::from_iter(self.rows.iter().map(|row| Vec::from_iter(row.iter().rev().copied()))) };
+    }
+
+    /// Every distinct rotation and reflection of the stencil.
+    pub fn orientations(&self) -> Vec<Stencil> {
+        let mut orientations: Vec<Stencil> = Vec::new();
+        for mut stencil in [self.clone(), self.reflect()] {
+            for _ in 0..4 {
+                if !orientations.contains(&stencil) {
+                    orientations.push(stencil.clone());
+                }
+                stencil = stencil.rotate();
+            }
+        }
+
+        return orientations;
+    }
+
+    // The whole stencil has to fit on the grid, wildcards included
+    fn matches_at(&self, grid: &[&[u8]], x: usize, y: usize) -> bool {
+        if y + self.rows.len() > grid.len() {
+            return false;
+        }
+        return self.rows.iter().enumerate().all(|(dy, row)| {
+            let line = grid[y + dy];
+            x + row.len() <= line.len() && row.iter().enumerate().all(|(dx, c)| *c == b'.' || line[x + dx] == *c)
+        });
+    }
+
+    /// The top-left corners of everywhere the stencil matches.
+    pub fn find(&self, grid: &[&[u8]]) -> Vec<Point> {
+        return Vec::from_iter((0..grid.len()).cartesian_product(0..grid.iter().map(|row| row.len()).max().unwrap_or(0))
+            .filter(|(y, x)| self.matches_at(grid, *x, *y))
+            .map(|(y, x)| (x as i64, y as i64)));
+    }
+}
+
+/// Where any of `stencils` matches, with the index of the one that did.
+pub fn find_stencils(stencils: &[Stencil], grid: &[&[u8]]) -> Vec<(usize, Point)> {
+    return Vec::from_iter(stencils.iter().enumerate().flat_map(|(ix, stencil)| stencil.find(grid).into_iter().map(move |p| (ix, p))));
+}
+
+pub const X_MAS: &str = "M.S/.A./M.S";
+
+pub fn part1(contents: &str) -> i64 {
+    return WordSearch::new(&["XMAS"]).find(&parse(contents)).len() as i64;
+}
+
+fn part1_step(contents: &str) -> i64 {
+    let char_map: HashMap<(i64, i64), char> = HashMap::from_iter(contents.lines().enumerate().flat_map(|(y, line)| {
+        line.chars().enumerate().map(move |(x, c)| ((x as i64, y as i64), c))
+    }));
+
+    let mut test: Vec<((i64, i64), (i64, i64))> = Vec::from_iter(char_map.iter().filter_map(|(k, v)| {
+        if *v == 'X' {
+            Some((-1..=1).cartesian_product(-1..=1).filter_map(|(x, y)| {
+                if x != 0 || y != 0 {
+                    Some(((k.0 + x, k.1 + y), (x, y)))
+                } else {
+                    None
+                }
+            }))
+        } else {
+            None
+        }
+    }).flatten());
+
+    for next_char in "MAS".chars() {
+        test = Vec::from_iter(test.iter().filter_map(|(pos, dir)| {
+            if *char_map.get(&pos).unwrap_or(&' ') == next_char {
+                Some(((pos.0 + dir.0, pos.1 + dir.1), *dir))
+            } else {
+                None
+            }
+        }))
+    }
+
+    return test.len() as i64;
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return find_stencils(&Stencil::parse(X_MAS).orientations(), &parse(contents)).len() as i64;
+}
+
+fn part2_centres(contents: &str) -> i64 {
+    let char_map: HashMap<(i64, i64), char> = HashMap::from_iter(contents.lines().enumerate().flat_map(|(y, line)| {
+        line.chars().enumerate().map(move |(x, c)| ((x as i64, y as i64), c))
+    }));
+
+    let mut test: Vec<((i64, i64), (i64, i64))> = Vec::from_iter(char_map.iter().filter_map(|(k, v)| {
+        if *v == 'M' {
+            Some((-1..=1).cartesian_product(-1..=1).filter_map(|(x, y)| {
+                if (x + y) % 2 == 0 && x != 0 && y != 0 {
+                    Some(((k.0 + x, k.1 + y), (x, y)))
+                } else {
+                    None
+                }
+            }))
+        } else {
+            None
+        }
+    }).flatten());
+
+    for next_char in "AS".chars() {
+        test = Vec::from_iter(test.iter().filter_map(|(pos, dir)| {
+            if *char_map.get(&pos).unwrap_or(&' ') == next_char {
+                Some(((pos.0 + dir.0, pos.1 + dir.1), *dir))
+            } else {
+                None
+            }
+        }))
+    }
+
+    let mut centers: HashMap<(i64, i64), i64> = HashMap::new();
+    for ((x, y), (dx, dy)) in test.iter() {
+        *centers.entry((x - (2*dx), y - (2*dy))).or_insert(0) += 1;
+    }
+    
+    return centers.iter().filter(|(_, v)| {
+        **v >= 2
+    }).count() as i64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 18);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 9);
+    }
+
+    #[test]
+    fn word_search_test() {
+        let mut search = WordSearch::new(&["XMAS", "AM"]);
+        let matches = search.find(&parse("XMAS\nSAMX"));
+        assert_eq!(matches.len(), 6);
+        assert!(matches.contains(&Match { word: 0, start: (3, 1), direction: (-1, 0) }));
+        assert!(matches.contains(&Match { word: 1, start: (1, 1), direction: (0, -1) }));
+
+        search.directions = vec![(1, 0)];
+        assert_eq!(search.find(&parse("XMAS\nSAMX")).len(), 2);
+        assert_eq!(search.find(&parse("MASX\n....")).len(), 0);
+        search.wrap = true;
+        assert_eq!(search.find(&parse("MASX\n....")), vec![Match { word: 0, start: (3, 0), direction: (1, 0) }]);
+
+        // A word can't run through the gap a short row leaves
+        let search = WordSearch::new(&["XMAS"]);
+        assert_eq!(search.find(&parse("XMAX\nM..M\nA\nS..S")), vec![Match { word: 0, start: (0, 0), direction: (0, 1) }]);
+    }
+
+    #[test]
+    fn stencil_test() {
+        let stencil = Stencil::parse("AB/C.");
+        assert_eq!(stencil.rotate(), Stencil::parse("CA/.B"));
+        assert_eq!(stencil.orientations().len(), 8);
+        assert_eq!(Stencil::parse(X_MAS).orientations().len(), 4);
+        assert_eq!(stencil.find(&parse("ABAB\nCXCA\nABC.")), vec![(0, 0), (2, 0)]);
+        assert_eq!(find_stencils(&Stencil::parse("AB/C.").orientations(), &parse("BA\n.C")), vec![(4, (0, 0))]);
+
+        // Wildcards can't hang off the right or bottom of the grid
+        assert_eq!(stencil.find(&parse("AB\nC")), vec![]);
+        assert_eq!(Stencil::parse("A/.").find(&parse("A.\nXA")), vec![(0, 0)]);
+        assert_eq!(Stencil::parse("A.").find(&parse("XA\nA")), vec![]);
+        assert_eq!(Stencil::parse("AB/C"), stencil);
+        assert_eq!(Stencil::parse("A/BC").orientations().len(), 8);
+    }
+
+    #[test]
+    fn variants_test() {
+        let contents = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\nXXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX";
+        assert_eq!(part1(contents), 18);
+        assert_eq!(part2(contents), 9);
+        assert!(aoc_common::agree(&aoc_common::time_variants(contents, VARIANTS)));
+    }
+}
@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+use std::cmp::Ordering;
+use aoc_common::{Assumption, Variant};
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption { name: "no rule cycles within an update", check: acyclic },
+];
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 1, name: "violations", solve: part1 },
+    Variant { part: 1, name: "comparator", solve: part1_comparator },
+    Variant { part: 2, name: "topological", solve: part2 },
+    Variant { part: 2, name: "comparator", solve: part2_comparator },
+];
+
+/// The `X|Y` ordering rules: page `X` must be printed before page `Y`.
+/// Each page gets a row of bits marking the pages that must follow it, so
+/// checking a rule is two index lookups and a bit test.
+pub struct Rules {
+    index: HashMap<i64, usize>,
+    words: usize,
+    after: Vec<u64>,
+}
+
+impl Rules {
+    pub fn parse(section: &str) -> Rules {
+        let mut index: HashMap<i64, usize> = HashMap::new();
+        let pairs = Vec::from_iter(section.lines().map(|line| {
+            let mut nums = line.split("|");
+            let left = nums.next().unwrap().parse::<i64>().unwrap();
+            let right = nums.next().unwrap().parse::<i64>().unwrap();
+            for page in [left, right] {
+                let next = index.len();
+                index.entry(page).or_insert(next);
+            }
+            (index[&left], index[&right])
+        }));
+
+        let words = index.len().div_ceil(64);
+        let mut after = vec![0; index.len() * words];
+        for (left, right) in pairs {
+            after[left * words + right / 64] |= 1 << (right % 64);
+        }
+        return Rules { index, words, after };
+    }
+
+    /// Whether a rule says `a` must come before `b`.
+    pub fn before(&self, a: i64, b: i64) -> bool {
+        return match (self.index.get(&a), self.index.get(&b)) {
+            (Some(&a), Some(&b)) => self.after[a * self.words + b / 64] & (1 << (b % 64)) != 0,
+            _ => false,
+        };
+    }
+
+    /// Every rule as an `(X, Y)` pair, sorted.
+    pub fn pairs(&self) -> Vec<(i64, i64)> {
+        let mut pairs = Vec::from_iter(self.index.keys().flat_map(|&a| {
+            self.index.keys().filter(move |&&b| self.before(a, b)).map(move |&b| (a, b))
+        }));
+        pairs.sort();
+        return pairs;
+    }
+}
+
+pub fn parse(contents: &str) -> (Rules, Vec<Vec<i64>>) {
+    let mut break_split = contents.split("\n\n");
+    let rules = Rules::parse(break_split.next().unwrap());
+    let updates = Vec::from_iter(break_split.next().unwrap().lines().map(|line| {
+        Vec::from_iter(line.split(',').map(|n| n.parse::<i64>().unwrap()))
+    }));
+    return (rules, updates);
+}
+
+/// Every rule `X|Y` the update breaks by printing `Y` before `X`.
+pub fn violations(rules: &Rules, update: &[i64]) -> Vec<(i64, i64)> {
+    let mut broken = Vec::new();
+    for (i, &earlier) in update.iter().enumerate() {
+        for &later in &update[i + 1..] {
+            if rules.before(later, earlier) {
+                broken.push((later, earlier));
+            }
+        }
+    }
+    return broken;
+}
+
+/// Orders the update's pages so every rule between them holds, considering
+/// only rules whose pages both appear in it. Whenever the rules allow a
+/// choice, the page printed earliest in the update goes next. If the rules
+/// can't be satisfied, returns a cycle of pages instead, each required to
+/// come before the next.
+pub fn topological_sort(rules: &Rules, update: &[i64]) -> Result<Vec<i64>, Vec<i64>> {
+    let mut in_degree = Vec::from_iter(update.iter().map(|&page| {
+        update.iter().filter(|&&other| rules.before(other, page)).count()
+    }));
+    let mut placed = vec![false; update.len()];
+    let mut sorted = Vec::with_capacity(update.len());
+
+    while sorted.len() < update.len() {
+        let next = (0..update.len()).find(|&i| !placed[i] && in_degree[i] == 0);
+        let Some(next) = next else {
+            return Err(find_cycle(rules, update, &placed));
+        };
+
+        placed[next] = true;
+        sorted.push(update[next]);
+        for (i, &page) in update.iter().enumerate() {
+            if !placed[i] && rules.before(update[next], page) {
+                in_degree[i] -= 1;
+            }
+        }
+    }
+
+    return Ok(sorted);
+}
+
+// Every unplaced page still has an unplaced page that must precede it, so
+// walking backwards along those rules has to revisit a page eventually
+fn find_cycle(rules: &Rules, update: &[i64], placed: &[bool]) -> Vec<i64> {
+    let mut path = vec![(0..update.len()).find(|&i| !placed[i]).unwrap()];
+    loop {
+        let current = update[*path.last().unwrap()];
+        let previous = (0..update.len()).find(|&i| !placed[i] && rules.before(update[i], current)).unwrap();
+        if let Some(start) = path.iter().position(|&i| i == previous) {
+            return Vec::from_iter(path[start..].iter().rev().map(|&i| update[i]));
+        }
+        path.push(previous);
+    }
+}
+
+fn sorted(rules: &Rules, update: &[i64]) -> Vec<i64> {
+    return topological_sort(rules, update)
+        .unwrap_or_else(|cycle| panic!("Rules form a cycle: {}", format_cycle(&cycle)));
+}
+
+/// The fewest pages to pick up and reinsert elsewhere to fix the update, in
+/// the order they're printed. The pages left in place can't include a pair
+/// that the rules, followed through the update's other pages, put the other
+/// way round, and any set without such a pair can stay put. Those conflicts
+/// order the pages, so by Dilworth's theorem the largest such set is as big
+/// as the fewest chains of conflicts covering the update, and a maximum
+/// matching between pages and the later pages they conflict with finds both.
+pub fn moves(rules: &Rules, update: &[i64]) -> Vec<i64> {
+    let n = update.len();
+
+    // Floyd-Warshall over the update's own pages: must_precede[a][b] when
+    // the rules chain page a before page b
+    let mut must_precede = Vec::from_iter(update.iter().map(|&a| {
+        Vec::from_iter(update.iter().map(|&b| rules.before(a, b)))
+    }));
+    for k in 0..n {
+        let through = must_precede[k].clone();
+        for row in must_precede.iter_mut().filter(|row| row[k]) {
+            for (b, &after) in through.iter().enumerate() {
+                row[b] |= after;
+            }
+        }
+    }
+
+    // conflicts[i] holds the later pages that must actually precede page i
+    let conflicts = Vec::from_iter((0..n).map(|i| {
+        Vec::from_iter((i + 1..n).filter(|&j| must_precede[j][i]))
+    }));
+
+    let mut matched = vec![None; n];
+    for i in 0..n {
+        augment(&conflicts, i, &mut vec![false; n], &mut matched);
+    }
+
+    // König: walk alternating paths from the pages left unmatched on the
+    // earlier side, and a page stays put if it's reached there but not as a
+    // later page
+    let mut matched_from = vec![None; n];
+    for (j, &i) in matched.iter().enumerate() {
+        if let Some(i) = i {
+            matched_from[i] = Some(j);
+        }
+    }
+    let mut reached_earlier = vec![false; n];
+    let mut reached_later = vec![false; n];
+    let mut stack = Vec::from_iter((0..n).filter(|&i| matched_from[i].is_none()));
+    while let Some(i) = stack.pop() {
+        if reached_earlier[i] {
+            continue;
+        }
+        reached_earlier[i] = true;
+        for &j in &conflicts[i] {
+            if !reached_later[j] {
+                reached_later[j] = true;
+                if let Some(next) = matched[j] {
+                    stack.push(next);
+                }
+            }
+        }
+    }
+
+    return Vec::from_iter((0..n).filter(|&i| !reached_earlier[i] || reached_later[i]).map(|i| update[i]));
+}
+
+// Kuhn's augmenting path search: matched[j] is the earlier page paired with
+// later page j
+fn augment(conflicts: &[Vec<usize>], i: usize, seen: &mut [bool], matched: &mut [Option<usize>]) -> bool {
+    for &j in &conflicts[i] {
+        if !seen[j] {
+            seen[j] = true;
+            if matched[j].is_none_or(|other| augment(conflicts, other, seen, matched)) {
+                matched[j] = Some(i);
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+fn acyclic(contents: &str) -> Result<(), String> {
+    let (rules, updates) = parse(contents);
+    for (n, update) in updates.iter().enumerate() {
+        if let Err(cycle) = topological_sort(&rules, update) {
+            return Err(format!("update {} has rule cycle {}", n + 1, format_cycle(&cycle)));
+        }
+    }
+    return Ok(());
+}
+
+fn format_cycle(cycle: &[i64]) -> String {
+    let mut pages = Vec::from_iter(cycle.iter().map(|page| page.to_string()));
+    pages.push(cycle[0].to_string());
+    return pages.join(" -> ");
+}
+
+pub fn part1_updates(rules: &Rules, updates: &[Vec<i64>]) -> i64 {
+    return updates.iter()
+        .filter(|update| violations(rules, update).is_empty())
+        .map(|update| update[update.len() / 2])
+        .sum::<i64>();
+}
+
+pub fn part2_updates(rules: &Rules, updates: &[Vec<i64>]) -> i64 {
+    return updates.iter()
+        .filter(|update| !violations(rules, update).is_empty())
+        .map(|update| {
+            let sorted = sorted(rules, update);
+            sorted[sorted.len() / 2]
+        })
+        .sum::<i64>();
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let (rules, updates) = parse(contents);
+    return part1_updates(&rules, &updates);
+}
+
+pub fn part2(contents: &str) -> i64 {
+    let (rules, updates) = parse(contents);
+    return part2_updates(&rules, &updates);
+}
+
+// Sorting with a comparator that calls unrelated pages equal only works when
+// the rules happen to order every pair within an update
+fn comparator_sorted(contents: &str) -> Vec<(Vec<i64>, Vec<i64>)> {
+    let (rules, updates) = parse(contents);
+    return Vec::from_iter(updates.into_iter().map(|list| {
+        let mut sorted_list = list.clone();
+        sorted_list.sort_by(|a, b| {
+            if rules.before(*a, *b) {
+                Ordering::Less
+            } else if rules.before(*b, *a) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+        (list, sorted_list)
+    }));
+}
+
+pub fn part1_comparator(contents: &str) -> i64 {
+    return comparator_sorted(contents).iter().filter_map(|(list, sorted_list)| {
+        if sorted_list == list {
+            Some(sorted_list[list.len() / 2])
+        } else {
+            None
+        }
+    }).sum::<i64>();
+}
+
+pub fn part2_comparator(contents: &str) -> i64 {
+    return comparator_sorted(contents).iter().filter_map(|(list, sorted_list)| {
+        if sorted_list == list {
+            None
+        } else {
+            Some(sorted_list[list.len() / 2])
+        }
+    }).sum::<i64>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const EXAMPLE: &str = "47|53\n97|13\n97|61\n97|47\n75|29\n61|13\n75|53\n29|13\n97|29\n53|29\n61|53\n\
+        97|53\n61|29\n47|13\n75|47\n97|75\n47|61\n75|61\n47|29\n75|13\n53|13\n\n\
+        75,47,61,53,29\n97,61,53,29,13\n75,29,13\n75,97,47,61,53\n61,13,29\n97,13,75,29,47\n";
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 143);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 123);
+    }
+
+    #[test]
+    fn variants_test() {
+        for variant in VARIANTS {
+            assert_eq!((variant.solve)(EXAMPLE), if variant.part == 1 { 143 } else { 123 }, "{}", variant.name);
+        }
+    }
+
+    #[test]
+    fn topological_sort_test() {
+        let (rules, updates) = parse(EXAMPLE);
+        assert_eq!(violations(&rules, &updates[0]), vec![]);
+        assert_eq!(violations(&rules, &updates[3]), vec![(97, 75)]);
+        assert_eq!(topological_sort(&rules, &updates[3]), Ok(vec![97, 75, 47, 61, 53]));
+        assert_eq!(topological_sort(&rules, &updates[5]), Ok(vec![97, 75, 47, 29, 13]));
+
+        // Pages only move as far as the rules make them
+        assert_eq!(topological_sort(&rules, &[13, 1, 29, 2]), Ok(vec![1, 29, 13, 2]));
+
+        let (rules, updates) = parse("1|2\n2|3\n3|1\n4|1\n\n4,1,2,3\n");
+        assert_eq!(topological_sort(&rules, &updates[0]), Err(vec![2, 3, 1]));
+        assert!(acyclic("1|2\n2|3\n3|1\n\n1,2\n").is_ok());
+        assert_eq!(acyclic("1|2\n2|3\n3|1\n\n1,2,3\n"), Err("update 1 has rule cycle 2 -> 3 -> 1 -> 2".to_string()));
+    }
+
+    #[test]
+    fn moves_test() {
+        let (rules, updates) = parse(EXAMPLE);
+        assert_eq!(moves(&rules, &updates[0]), vec![]);
+        assert_eq!(moves(&rules, &updates[3]), vec![75]);
+        assert_eq!(moves(&rules, &updates[4]), vec![13]);
+        assert_eq!(moves(&rules, &updates[5]), vec![13, 29]);
+
+        // Fixing the sorted order's longest run would move 2 or 3 as well
+        let (rules, updates) = parse("1|2\n1|3\n\n2,3,4,1\n");
+        assert_eq!(moves(&rules, &updates[0]), vec![1]);
+
+        // 1 has to precede 3 through 2, so moving just 2 can't work
+        let (rules, updates) = parse("1|2\n2|3\n\n3,4,1,2\n");
+        assert_eq!(violations(&rules, &updates[0]), vec![(2, 3)]);
+        assert_eq!(moves(&rules, &updates[0]), vec![3]);
+
+        // Rows span several words once there are more than 64 pages
+        let chain = Vec::from_iter((0..130).map(|page| format!("{}|{}", page, page + 1)));
+        let rules = Rules::parse(&chain.join("\n"));
+        assert_eq!(rules.pairs().len(), 130);
+        assert_eq!(rules.pairs()[..2], [(0, 1), (1, 2)]);
+        assert!(rules.before(70, 71) && rules.before(129, 130));
+        assert!(!rules.before(71, 70) && !rules.before(70, 72) && !rules.before(5, 500));
+    }
+}
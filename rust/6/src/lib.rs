@@ -0,0 +1,687 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::thread;
+use std::thread::available_parallelism;
+use aoc_common::{Assumption, Variant};
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption { name: "a map with a guard on it", check: has_guard },
+];
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 1, name: "route", solve: part1 },
+    Variant { part: 1, name: "cells", solve: part1_cells },
+    Variant { part: 2, name: "jump_table", solve: part2 },
+    Variant { part: 2, name: "brute_force", solve: part2_brute_force },
+];
+
+/// Up, right, down and left, so turning right is the next one along.
+const DIRECTIONS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The lab as a flat grid of cells, `y * width + x`. Alongside the map it
+/// keeps a jump table: for every open cell and direction, the cell where a
+/// guard walking that way stops in front of an obstacle, or `None` if they
+/// walk off the map first.
+pub struct Lab {
+    pub width: usize,
+    pub height: usize,
+    pub open: Vec<bool>,
+    /// The last guard on the map, the one both parts follow. Puzzle inputs
+    /// only have one, but this is the one the cell-by-cell variants pick too.
+    pub guard: (usize, usize),
+    pub guards: Vec<(usize, usize)>,
+    jumps: [Vec<Option<usize>>; 4],
+}
+
+impl Lab {
+    pub fn parse(contents: &str) -> Result<Lab, String> {
+        let mut open = Vec::new();
+        let mut guards = Vec::new();
+        let mut height = 0;
+        for line in contents.lines() {
+            for c in line.chars() {
+                if let Some(facing) = "^>v<".find(c) {
+                    guards.push((open.len(), facing));
+                }
+                open.push(c != '#');
+            }
+            height += 1;
+        }
+
+        if height == 0 {
+            return Err("the map is empty".to_string());
+        }
+        let Some(&guard) = guards.last() else {
+            return Err("there's no guard on the map".to_string());
+        };
+
+        let width = open.len() / height;
+        let mut lab = Lab { width, height, open, guard, guards, jumps: Default::default() };
+        lab.jumps = lab.build_jumps();
+        return Ok(lab);
+    }
+
+    /// The cell one step from `cell` in direction `facing`, if it's on the map.
+    pub fn step(&self, cell: usize, facing: usize) -> Option<usize> {
+        let (x, y) = ((cell % self.width) as i64, (cell / self.width) as i64);
+        let (dx, dy) = DIRECTIONS[facing];
+        let (x, y) = (x + dx, y + dy);
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return None;
+        }
+        return Some(y as usize * self.width + x as usize);
+    }
+
+    // Each entry only depends on the next cell along, so sweeping from the
+    // edge the guard would walk towards fills a direction in one pass
+    fn build_jumps(&self) -> [Vec<Option<usize>>; 4] {
+        return std::array::from_fn(|facing| {
+            let mut jumps = vec![None; self.open.len()];
+            let towards_start = facing == 0 || facing == 3;
+            let cells: Box<dyn Iterator<Item = usize>> = if towards_start {
+                Box::new(0..self.open.len())
+            } else {
+                Box::new((0..self.open.len()).rev())
+            };
+            for cell in cells {
+                jumps[cell] = match self.step(cell, facing) {
+                    None => None,
+                    Some(next) if !self.open[next] => Some(cell),
+                    Some(next) => jumps[next],
+                };
+            }
+            jumps
+        });
+    }
+
+    /// Where a guard at `cell` walking in direction `facing` stops, with an
+    /// extra obstacle at `added`. Only the jumps in the obstacle's row and
+    /// column that pass through it change, so rather than patching the
+    /// table, each jump checks whether the obstacle cuts it short.
+    pub fn jump(&self, cell: usize, facing: usize, added: Option<usize>) -> Option<usize> {
+        let stop = self.jumps[facing][cell];
+        let Some(added) = added else {
+            return stop;
+        };
+
+        let distance = |to: usize| -> Option<usize> {
+            let (x, y) = (cell % self.width, cell / self.width);
+            let (to_x, to_y) = (to % self.width, to / self.width);
+            return match facing {
+                0 if to_x == x && to_y < y => Some(y - to_y),
+                1 if to_y == y && to_x > x => Some(to_x - x),
+                2 if to_x == x && to_y > y => Some(to_y - y),
+                3 if to_y == y && to_x < x => Some(x - to_x),
+                _ => None,
+            };
+        };
+
+        return match (distance(added), stop) {
+            (Some(ahead), Some(stop)) if ahead > distance(stop).unwrap_or(0) => Some(stop),
+            (Some(_), _) => self.step(added, (facing + 2) % 4),
+            (None, stop) => stop,
+        };
+    }
+
+    /// Every step of the guard's patrol until they leave the map: each cell
+    /// they enter, along with the direction they entered it in.
+    pub fn route(&self) -> Vec<(usize, usize)> {
+        let (mut cell, mut facing) = self.guard;
+        let mut route = vec![(cell, facing)];
+        let mut seen = HashSet::from([(cell, facing)]);
+        while let Some(next) = self.step(cell, facing) {
+            if !self.open[next] {
+                facing = (facing + 1) % 4;
+            } else {
+                cell = next;
+                route.push((cell, facing));
+            }
+            if !seen.insert((cell, facing)) {
+                panic!("The guard never leaves the map");
+            }
+        }
+        return route;
+    }
+
+    /// Whether a guard at `cell` facing `facing` gets stuck in a loop once an
+    /// obstacle is added at `added`. Only the turning points are recorded,
+    /// in `seen`, using a fresh `stamp` per call so it never needs clearing.
+    pub fn loops(&self, mut cell: usize, mut facing: usize, added: usize, seen: &mut [[u32; 4]], stamp: u32) -> bool {
+        while let Some(stop) = self.jump(cell, facing, Some(added)) {
+            if seen[stop][facing] == stamp {
+                return true;
+            }
+            seen[stop][facing] = stamp;
+            (cell, facing) = (stop, (facing + 1) % 4);
+        }
+        return false;
+    }
+
+    /// Every open cell where an added obstacle traps the guard in a loop, in
+    /// the order the guard would first walk into them.
+    pub fn loop_obstacles(&self) -> Vec<usize> {
+        let route = self.route();
+
+        // An obstacle only changes anything from the first time the guard would
+        // walk into it, so each candidate is simulated from the step before
+        let mut tried = vec![false; self.open.len()];
+        tried[self.guard.0] = true;
+        let mut seen = vec![[0; 4]; self.open.len()];
+        let mut obstacles = Vec::new();
+        for (i, &(cell, facing)) in route.iter().enumerate().skip(1) {
+            if tried[cell] {
+                continue;
+            }
+            tried[cell] = true;
+            if self.loops(route[i - 1].0, facing, cell, &mut seen, i as u32) {
+                obstacles.push(cell);
+            }
+        }
+        return obstacles;
+    }
+
+    /// The loop the guard ends up in with an obstacle added at `obstacle`,
+    /// or `None` if they still leave. Walks one step (a move or a turn) at a
+    /// time, remembering when each `(cell, facing)` was first visited.
+    pub fn witness(&self, obstacle: usize) -> Option<Witness> {
+        let (mut cell, mut facing) = self.guard;
+        let mut visited: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut steps = Vec::new();
+        while !visited.contains_key(&(cell, facing)) {
+            visited.insert((cell, facing), steps.len());
+            steps.push((cell, facing));
+            let next = self.step(cell, facing)?;
+            if !self.open[next] || next == obstacle {
+                facing = (facing + 1) % 4;
+            } else {
+                cell = next;
+            }
+        }
+
+        let entry = visited[&(cell, facing)];
+        let cycle = steps.split_off(entry);
+        let mut turns: Vec<usize> = Vec::new();
+        for (i, &(cell, facing)) in cycle.iter().enumerate() {
+            let after = cycle[(i + 1) % cycle.len()].1;
+            if after != facing && turns.last() != Some(&cell) {
+                turns.push(cell);
+            }
+        }
+        return Some(Witness { obstacle, entry, cycle, turns });
+    }
+
+    pub fn position(&self, cell: usize) -> (usize, usize) {
+        return (cell % self.width, cell / self.width);
+    }
+}
+
+/// How a guard gets stuck once an obstacle is added: they first reach the
+/// loop after `entry` steps, then repeat `cycle` forever, turning at `turns`.
+#[derive(Debug, PartialEq)]
+pub struct Witness {
+    pub obstacle: usize,
+    pub entry: usize,
+    pub cycle: Vec<(usize, usize)>,
+    pub turns: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Export {
+    None,
+    List,
+    Json,
+    Map,
+}
+
+impl FromStr for Export {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Export, String> {
+        match s {
+            "none" => Ok(Export::None),
+            "list" => Ok(Export::List),
+            "json" => Ok(Export::Json),
+            "map" => Ok(Export::Map),
+            _ => Err(format!("Unknown export format {}", s)),
+        }
+    }
+}
+
+pub fn export(lab: &Lab, witnesses: &[Witness], format: Export) -> String {
+    let position = |cell: usize| lab.position(cell);
+    return match format {
+        Export::None => String::new(),
+        Export::List => Vec::from_iter(witnesses.iter().map(|w| {
+            let turns = Vec::from_iter(w.turns.iter().map(|&cell| format!("{:?}", position(cell))));
+            format!(
+                "Obstacle at {:?}: loop entered after {} steps, {} steps long, turning at {}",
+                position(w.obstacle), w.entry, w.cycle.len(), turns.join(" ")
+            )
+        })).join("\n"),
+        Export::Json => {
+            let entries = Vec::from_iter(witnesses.iter().map(|w| {
+                let point = |cell: usize| format!("[{}, {}]", position(cell).0, position(cell).1);
+                let turns = Vec::from_iter(w.turns.iter().map(|&cell| point(cell)));
+                format!(
+                    "  {{\"obstacle\": {}, \"entry\": {}, \"length\": {}, \"turns\": [{}]}}",
+                    point(w.obstacle), w.entry, w.cycle.len(), turns.join(", ")
+                )
+            }));
+            format!("[\n{}\n]", entries.join(",\n"))
+        }
+        Export::Map => overlay(lab, witnesses),
+    };
+}
+
+/// The map with each witness's obstacle drawn as `O` and its loop traced
+/// over the floor: `|` and `-` along the way and `+` where it turns.
+fn overlay(lab: &Lab, witnesses: &[Witness]) -> String {
+    let mut map = Vec::from_iter(lab.open.iter().map(|&open| if open { '.' } else { '#' }));
+    for witness in witnesses {
+        for &(cell, facing) in &witness.cycle {
+            let line = if facing % 2 == 0 { '|' } else { '-' };
+            map[cell] = match map[cell] {
+                '.' if witness.turns.contains(&cell) => '+',
+                '.' => line,
+                c if c == line || c == 'O' => c,
+                _ => '+',
+            };
+        }
+    }
+    for witness in witnesses {
+        map[witness.obstacle] = 'O';
+    }
+    map[lab.guard.0] = "^>v<".as_bytes()[lab.guard.1] as char;
+
+    return Vec::from_iter(map.chunks(lab.width).map(String::from_iter)).join("\n");
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Turn {
+    Right,
+    Left,
+    Reverse,
+    /// Right at the first obstacle, left at the next, and so on.
+    Alternating,
+}
+
+impl Turn {
+    fn apply(self, facing: usize, turns: usize) -> usize {
+        return match self {
+            Turn::Right => (facing + 1) % 4,
+            Turn::Left => (facing + 3) % 4,
+            Turn::Reverse => (facing + 2) % 4,
+            Turn::Alternating if turns.is_multiple_of(2) => (facing + 1) % 4,
+            Turn::Alternating => (facing + 3) % 4,
+        };
+    }
+}
+
+impl FromStr for Turn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Turn, String> {
+        match s {
+            "right" => Ok(Turn::Right),
+            "left" => Ok(Turn::Left),
+            "reverse" => Ok(Turn::Reverse),
+            "alternating" => Ok(Turn::Alternating),
+            _ => Err(format!("Unknown turning rule {}", s)),
+        }
+    }
+}
+
+/// Flips `cell` between open floor and obstacle before step `step`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Toggle {
+    pub step: usize,
+    pub cell: usize,
+}
+
+/// Parses toggles written `x,y@step`, separated by `;`.
+pub fn toggles(lab: &Lab, s: &str) -> Vec<Toggle> {
+    return Vec::from_iter(s.split(';').filter(|t| !t.is_empty()).map(|toggle| {
+        let parse = || -> Option<Toggle> {
+            let (position, step) = toggle.split_once('@')?;
+            let (x, y) = position.split_once(',')?;
+            let (x, y) = (x.trim().parse::<usize>().ok()?, y.trim().parse::<usize>().ok()?);
+            if x >= lab.width || y >= lab.height {
+                return None;
+            }
+            return Some(Toggle { step: step.trim().parse().ok()?, cell: y * lab.width + x });
+        };
+        parse().unwrap_or_else(|| panic!("Invalid toggle {}", toggle))
+    }));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Outcome {
+    /// Walked off the map on this step.
+    Left(usize),
+    /// Found to be repeating themselves on this step.
+    Looped(usize),
+    /// Still going when the simulation stopped.
+    Walking,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GuardReport {
+    pub start: (usize, usize),
+    pub visited: usize,
+    pub outcome: Outcome,
+}
+
+/// Two guards on the same cell after a step, or passing through each other.
+#[derive(Debug, PartialEq)]
+pub struct Collision {
+    pub step: usize,
+    pub guards: (usize, usize),
+    pub cell: usize,
+}
+
+struct Walker {
+    cell: usize,
+    facing: usize,
+    turns: usize,
+    visited: HashSet<usize>,
+    seen: HashSet<(usize, usize, usize)>,
+    outcome: Outcome,
+}
+
+/// Walks every guard on the map together, one step (a move or a turn) each
+/// per step, for at most `max_steps` steps. Guards don't block each other;
+/// meeting is only reported. A guard can only be caught in a loop once every
+/// toggle has happened, since until then the map might still change.
+pub fn patrol(lab: &Lab, turn: Turn, toggles: &[Toggle], max_steps: usize) -> (Vec<GuardReport>, Vec<Collision>) {
+    let mut open = lab.open.clone();
+    let settled = toggles.iter().map(|t| t.step).max().unwrap_or(0);
+    let mut walkers = Vec::from_iter(lab.guards.iter().map(|&(cell, facing)| Walker {
+        cell, facing, turns: 0, visited: HashSet::from([cell]), seen: HashSet::new(), outcome: Outcome::Walking,
+    }));
+    let mut collisions = Vec::new();
+
+    for step in 0..max_steps {
+        if walkers.iter().all(|w| w.outcome != Outcome::Walking) {
+            break;
+        }
+        for toggle in toggles.iter().filter(|t| t.step == step) {
+            open[toggle.cell] = !open[toggle.cell];
+        }
+
+        let before = Vec::from_iter(walkers.iter().map(|w| w.cell));
+        let on_map = |w: &Walker| !matches!(w.outcome, Outcome::Left(_));
+        // Looped guards keep walking, since they can still run into the others
+        for walker in walkers.iter_mut().filter(|w| on_map(w)) {
+            // Alternating guards also have to be on the same side of a turn
+            let parity = if turn == Turn::Alternating { walker.turns % 2 } else { 0 };
+            if walker.outcome == Outcome::Walking && step >= settled
+                && !walker.seen.insert((walker.cell, walker.facing, parity)) {
+                walker.outcome = Outcome::Looped(step);
+            }
+
+            match lab.step(walker.cell, walker.facing) {
+                None => walker.outcome = Outcome::Left(step),
+                Some(next) if !open[next] => {
+                    walker.facing = turn.apply(walker.facing, walker.turns);
+                    walker.turns += 1;
+                }
+                Some(next) => {
+                    walker.cell = next;
+                    walker.visited.insert(next);
+                }
+            }
+        }
+
+        // Guards walking together only collide once, when they first meet
+        for i in 0..walkers.len() {
+            for j in i + 1..walkers.len() {
+                let (a, b) = (&walkers[i], &walkers[j]);
+                let met = a.cell == b.cell && before[i] != before[j];
+                let passed = a.cell == before[j] && b.cell == before[i] && a.cell != b.cell;
+                if on_map(a) && on_map(b) && (met || passed) {
+                    collisions.push(Collision { step, guards: (i, j), cell: a.cell });
+                }
+            }
+        }
+    }
+
+    let reports = Vec::from_iter(lab.guards.iter().zip(walkers).map(|(&start, walker)| {
+        GuardReport { start, visited: walker.visited.len(), outcome: walker.outcome }
+    }));
+    return (reports, collisions);
+}
+
+fn has_guard(contents: &str) -> Result<(), String> {
+    return Lab::parse(contents).map(|_| ());
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let lab = Lab::parse(contents).unwrap();
+    let cells: HashSet<usize> = HashSet::from_iter(lab.route().iter().map(|(cell, _)| *cell));
+    return cells.len() as i64;
+}
+
+pub fn part2(contents: &str) -> i64 {
+    let lab = Lab::parse(contents).unwrap();
+    return lab.loop_obstacles().len() as i64;
+}
+
+// Each position the guard has been in, with the way they were facing
+type Visited = HashSet<((i64, i64), (i64, i64))>;
+
+fn guard_movement(pos: (i64, i64), facing: (i64, i64), spaces: &HashMap<(i64, i64), bool>, visited: &mut Visited, add_obstacles: &mut HashSet<(i64, i64)>) -> ((i64, i64), (i64, i64), Option<bool>) {
+    if !spaces.contains_key(&pos) {
+        return (pos, facing, Some(false));
+    }
+
+    if visited.contains(&(pos, facing)) {
+        return (pos, facing, Some(true));
+    }
+    visited.insert((pos, facing));
+
+    let forward: (i64, i64) = (pos.0 + facing.0, pos.1 + facing.1);
+    let right: (i64, i64) = (-facing.1, facing.0);
+    
+    return if *spaces.get(&forward).unwrap_or(&true) {
+        add_obstacles.insert(forward);
+        (forward, facing, None)
+    } else {
+        (pos, right, None)
+    };
+}
+
+pub fn part1_cells(contents: &str) -> i64 {
+    let mut start_pos: Option<((i64, i64), (i64, i64))> = None;
+    let mut spaces: HashMap<(i64, i64), bool> = HashMap::new();
+    
+    for (y, line) in contents.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            spaces.insert((x as i64, y as i64), c != '#');
+            match c {
+                '^' => {start_pos = Some(((x as i64, y as i64), (0, -1)));},
+                'v' => {start_pos = Some(((x as i64, y as i64), (0, 1)));},
+                '>' => {start_pos = Some(((x as i64, y as i64), (1, 0)));},
+                '<' => {start_pos = Some(((x as i64, y as i64), (-1, 0)));},
+                _ => ()
+            }
+        }
+    }
+    
+    let mut visited: Visited = HashSet::new();
+    let (mut pos, mut facing) = start_pos.unwrap();
+    let mut finished: Option<bool> = None;
+
+    while finished.is_none() {
+        (pos, facing, finished) = guard_movement(pos, facing, &spaces, &mut visited, &mut HashSet::new());
+    }
+
+    let actual_visited: HashSet<(i64, i64)> = HashSet::from_iter(
+        visited.iter().map(|(p, _f)| *p)
+    );
+    return actual_visited.len() as i64;
+}
+
+pub fn part2_brute_force(contents: &str) -> i64 {
+    let mut start_pos: Option<((i64, i64), (i64, i64))> = None;
+    let mut spaces: HashMap<(i64, i64), bool> = HashMap::new();
+    
+    for (y, line) in contents.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            spaces.insert((x as i64, y as i64), c != '#');
+            match c {
+                '^' => {start_pos = Some(((x as i64, y as i64), (0, -1)));},
+                'v' => {start_pos = Some(((x as i64, y as i64), (0, 1)));},
+                '>' => {start_pos = Some(((x as i64, y as i64), (1, 0)));},
+                '<' => {start_pos = Some(((x as i64, y as i64), (-1, 0)));},
+                _ => ()
+            }
+        }
+    }
+    
+    let mut visited: Visited = HashSet::new();
+    let (mut pos, mut facing) = start_pos.unwrap();
+    let mut finished: Option<bool> = None;
+    let mut check_obstacles: HashSet<(i64, i64)> = HashSet::new();
+
+    while finished.is_none() {
+        (pos, facing, finished) = guard_movement(pos, facing, &spaces, &mut visited, &mut check_obstacles);
+    }
+
+    let num_cpus = available_parallelism().unwrap().get();
+    let num_per_thread = (check_obstacles.len() / num_cpus) + 1;
+    let threads: Vec<_> = (0..num_cpus).map(|n| {
+        let spaces = spaces.clone();
+        let check_obstacles = check_obstacles.clone();
+        let start_pos = start_pos.unwrap();
+        
+        thread::spawn(move || {
+            let mut count: i64 = 0;
+            for obstacle_pos in check_obstacles.iter().skip(n * num_per_thread).take(num_per_thread) {
+                let mut test_spaces = spaces.clone();
+                test_spaces.insert(*obstacle_pos, false);
+                let (mut test_pos, mut test_facing) = start_pos;
+                let mut test_finished = None;
+                let mut test_visited: Visited = HashSet::new();
+                while test_finished.is_none() {
+                    (test_pos, test_facing, test_finished) = guard_movement(test_pos, test_facing, &test_spaces, &mut test_visited, &mut HashSet::new());
+                }
+        
+                count += test_finished.unwrap() as i64;
+            }
+
+            count
+        })
+    }).collect();
+
+    return threads.into_iter().map(|t| t.join().unwrap()).sum::<i64>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    const EXAMPLE: &str = "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+        ..........\n.#..^.....\n........#.\n#.........\n......#...\n";
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 41);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 6);
+    }
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(has_guard(""), Err("the map is empty".to_string()));
+        assert_eq!(has_guard("..#\n..."), Err("there's no guard on the map".to_string()));
+
+        // Every variant follows the last guard
+        for variant in VARIANTS.iter().filter(|variant| variant.part == 1) {
+            assert_eq!((variant.solve)("^.v\n..."), 2, "{}", variant.name);
+        }
+    }
+
+    #[test]
+    fn variants_test() {
+        for variant in VARIANTS {
+            assert_eq!((variant.solve)(EXAMPLE), if variant.part == 1 { 41 } else { 6 }, "{}", variant.name);
+        }
+    }
+
+    #[test]
+    fn jump_test() {
+        let lab = Lab::parse(EXAMPLE).unwrap();
+        assert_eq!(lab.guard, (64, 0));
+        assert_eq!(lab.jump(64, 0, None), Some(14));
+        assert_eq!(lab.jump(14, 1, None), Some(18));
+        assert_eq!(lab.jump(18, 3, None), None);
+
+        // An obstacle only matters if it's ahead and nearer than the next one
+        assert_eq!(lab.jump(64, 0, Some(34)), Some(44));
+        assert_eq!(lab.jump(64, 0, Some(4)), Some(14));
+        assert_eq!(lab.jump(64, 0, Some(65)), Some(14));
+        assert_eq!(lab.jump(18, 3, Some(10)), Some(11));
+
+        // Adding an obstacle at (3, 6) traps the guard
+        let mut seen = vec![[0; 4]; lab.open.len()];
+        assert!(lab.loops(64, 3, 63, &mut seen, 1));
+        assert!(!lab.loops(64, 0, 54, &mut seen, 2));
+    }
+
+    #[test]
+    fn witness_test() {
+        let lab = Lab::parse(EXAMPLE).unwrap();
+        assert_eq!(lab.loop_obstacles(), vec![63, 76, 83, 81, 77, 97]);
+        assert_eq!(lab.witness(54), None);
+
+        let witness = lab.witness(76).unwrap();
+        assert_eq!((witness.entry, witness.cycle.len()), (19, 16));
+        assert_eq!(witness.turns, vec![62, 42, 46, 66]);
+
+        let witnesses = [lab.witness(63).unwrap()];
+        assert_eq!(
+            export(&lab, &witnesses, Export::Json),
+            "[\n  {\"obstacle\": [3, 6], \"entry\": 0, \"length\": 22, \"turns\": [[4, 1], [8, 1], [8, 6], [4, 6]]}\n]"
+        );
+        let map = export(&lab, &witnesses, Export::Map);
+        assert_eq!(map.lines().nth(1), Some("....+---+#"));
+        assert_eq!(map.lines().nth(6), Some(".#.O^---+."));
+    }
+
+    #[test]
+    fn patrol_test() {
+        let lab = Lab::parse(EXAMPLE).unwrap();
+        let (reports, collisions) = patrol(&lab, Turn::Right, &[], 1000);
+        assert_eq!(reports, vec![GuardReport { start: (64, 0), visited: 41, outcome: Outcome::Left(54) }]);
+        assert_eq!(collisions, vec![]);
+
+        // The obstacle from the first witness, but only for a while
+        let (reports, _) = patrol(&lab, Turn::Right, &toggles(&lab, "3,6@0"), 1000);
+        assert_eq!(reports[0].outcome, Outcome::Looped(22));
+        let (reports, _) = patrol(&lab, Turn::Right, &toggles(&lab, "3,6@0;3,6@30"), 1000);
+        assert!(matches!(reports[0].outcome, Outcome::Left(_)));
+
+        // Back down the way they came
+        let (reports, _) = patrol(&lab, Turn::Reverse, &[], 1000);
+        assert_eq!((reports[0].visited, reports[0].outcome), (9, Outcome::Left(14)));
+        assert_eq!(Vec::from_iter((0..4).map(|turns| Turn::Alternating.apply(0, turns))), vec![1, 3, 1, 3]);
+
+        let lab = Lab::parse(".>..<.\n").unwrap();
+        assert_eq!(lab.guards, vec![(1, 1), (4, 3)]);
+        assert_eq!(lab.guard, (4, 3));
+        let (reports, collisions) = patrol(&lab, Turn::Right, &[], 1000);
+        assert_eq!(collisions, vec![Collision { step: 1, guards: (0, 1), cell: 3 }]);
+        assert_eq!((reports[0].visited, reports[0].outcome), (5, Outcome::Left(4)));
+        assert_eq!((reports[1].visited, reports[1].outcome), (5, Outcome::Left(4)));
+    }
+}
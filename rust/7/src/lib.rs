@@ -0,0 +1,83 @@
+use aoc_common::overflow;
+
+fn can_make(nums: Vec<i64>, goal: i64, p2: bool) -> bool {
+    if nums.len() == 1 {
+        return goal == nums[0];
+    }
+
+    let last_ix = nums.len() - 1;
+
+    if p2 {
+        let goal_str = format!("{}", goal);
+        let last_str = format!("{}", nums[last_ix]);
+        if goal_str.len() >= last_str.len() && goal_str.ends_with(&last_str) && can_make(nums[..last_ix].to_vec(), goal_str[..goal_str.len()-last_str.len()].parse::<i64>().unwrap_or(0), p2) {
+            return true;
+        }
+    }
+
+    if goal % nums[last_ix] == 0 && can_make(nums[..last_ix].to_vec(), goal / nums[last_ix], p2) {
+        return true;
+    }
+
+    return can_make(nums[..last_ix].to_vec(), goal - nums[last_ix], p2);
+}
+
+fn equation(line: &str) -> (i64, Vec<i64>) {
+    let mut sides = line.split(": ");
+    let goal = sides.next().unwrap().parse::<i64>().unwrap();
+    let nums = Vec::from_iter(sides.next().unwrap().split_whitespace().map(|v| v.parse::<i64>().unwrap()));
+    return (goal, nums);
+}
+
+pub fn parse(contents: &str) -> Vec<(i64, Vec<i64>)> {
+    return Vec::from_iter(contents.lines().map(equation));
+}
+
+fn calibration(lines: impl Iterator<Item = impl AsRef<str>>, p2: bool) -> i64 {
+    return overflow::sum(lines.filter_map(|line| {
+        let (goal, nums) = equation(line.as_ref());
+        if can_make(nums, goal, p2) {
+            Some(goal)
+        } else {
+            None
+        }
+    }));
+}
+
+pub fn part1_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    return calibration(lines, false);
+}
+
+pub fn part2_lines(lines: impl Iterator<Item = impl AsRef<str>>) -> i64 {
+    return calibration(lines, true);
+}
+
+pub fn part1(contents: &str) -> i64 {
+    return part1_lines(contents.lines());
+}
+
+pub fn part2(contents: &str) -> i64 {
+    return part2_lines(contents.lines());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 3749);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 11387);
+    }
+}
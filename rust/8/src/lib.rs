@@ -0,0 +1,104 @@
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use aoc_common::Assumption;
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption { name: "square grid", check: square_grid },
+];
+
+fn square_grid(contents: &str) -> Result<(), String> {
+    let height = contents.lines().count();
+    return match contents.lines().enumerate().find(|(_, line)| line.chars().count() != height) {
+        Some((y, line)) => Err(format!("row {} is {} wide but the grid is {} tall", y, line.chars().count(), height)),
+        None => Ok(()),
+    };
+}
+
+/// Each frequency's antenna positions.
+pub fn parse(contents: &str) -> HashMap<char, HashSet<(i64, i64)>> {
+    let mut antennas: HashMap<char, HashSet<(i64, i64)>> = HashMap::new();
+    for (y, line) in contents.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c != '.' {
+                antennas.entry(c).or_insert(HashSet::new()).insert((x as i64, y as i64));
+            }
+        }
+    }
+    return antennas;
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let antennas = parse(contents);
+    
+    let mut antinodes: HashSet<(i64, i64)> = HashSet::new();
+    for antenna_group in antennas.values() {
+        for comb in antenna_group.iter().combinations(2) {
+            let a1 = comb[0];
+            let a2 = comb[1];
+            let dx = a2.0 - a1.0;
+            let dy = a2.1 - a1.1;
+            antinodes.insert((a1.0 - dx, a1.1 - dy));
+            antinodes.insert((a2.0 + dx, a2.1 + dy));
+        }
+    }
+
+    return antinodes.iter().filter(|n| {
+        n.0 >= 0 && n.0 < contents.lines().count() as i64 &&
+        n.1 >= 0 && n.1 < contents.lines().count() as i64
+    }).count() as i64;
+}
+
+pub fn part2(contents: &str) -> i64 {
+    let antennas = parse(contents);
+    let max_size = contents.lines().count() as i64;
+    
+    let mut antinodes: HashSet<(i64, i64)> = HashSet::new();
+    for antenna_group in antennas.values() {
+        for comb in antenna_group.iter().combinations(2) {
+            let mut a1: (i64, i64) = *comb[0];
+            let mut a2: (i64, i64) = *comb[1];
+            let dx = a2.0 - a1.0;
+            let dy = a2.1 - a1.1;
+
+            while a1.0 >= 0 && a1.0 < max_size && a1.1 >= 0 && a1.1 < max_size {
+                antinodes.insert(a1.clone());
+                a1 = (a1.0 - dx, a1.1 - dy);
+            }
+
+            while a2.0 >= 0 && a2.0 < max_size && a2.1 >= 0 && a2.1 < max_size {
+                antinodes.insert(a2.clone());
+                a2 = (a2.0 + dx, a2.1 + dy);
+            }
+        }
+    }
+
+    return antinodes.len() as i64;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 14);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 34);
+    }
+
+    #[test]
+    fn square_grid_test() {
+        assert!(square_grid("a..\n...\n..a").is_ok());
+        assert!(square_grid("a...\n....\n...a").is_err());
+    }
+}
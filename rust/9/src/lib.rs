@@ -0,0 +1,183 @@
+use aoc_common::Variant;
+
+pub const VARIANTS: &[Variant<i64>] = &[
+    Variant { part: 1, name: "insert", solve: part1 },
+    Variant { part: 1, name: "two_pointer", solve: part1_two_pointer },
+    Variant { part: 2, name: "insert", solve: part2 },
+    Variant { part: 2, name: "gap_scan", solve: part2_gap_scan },
+];
+
+#[derive(Debug, Clone, Copy)]
+struct FileBlock {
+    start: i64,
+    size: i64,
+    id: i64,
+}
+
+impl FileBlock {
+    fn new(start: i64, size: i64, id: i64) -> FileBlock {
+        FileBlock {
+            start: start,
+            size: size,
+            id: id,
+        }
+    }
+
+    fn checksum(&self) -> i64 {
+        (self.start..self.start+self.size).map(|n| n * self.id).sum::<i64>()
+    }
+}
+
+/// The disk map: alternating file and free space sizes.
+pub fn parse(contents: &str) -> Vec<i64> {
+    return Vec::from_iter(contents.lines().next().unwrap().chars().map(|n| (n as u32 - '0' as u32) as i64));
+}
+
+pub fn part1(contents: &str) -> i64 {
+    let mut blocks: Vec<FileBlock> = Vec::new();
+    let mut pos: i64 = 0;
+    for (ix, size) in parse(contents).into_iter().enumerate() {
+        if ix % 2 == 0 {
+            blocks.push(FileBlock::new(pos, size, ix as i64 / 2));
+        }
+        pos += size;
+    }
+
+    let mut start_ix: usize = 0;
+    let mut end_ix: usize = blocks.len() - 1;
+    let mut free: i64 = 0;
+
+    loop {
+        while start_ix < end_ix {
+            free = blocks[start_ix + 1].start - (blocks[start_ix].start + blocks[start_ix].size);
+            if free > 0 {
+                break;
+            }
+            start_ix += 1;
+        }
+
+        if start_ix >= end_ix {
+            break;
+        }
+        
+        let moving = free.min(blocks[end_ix].size);
+        blocks.insert(start_ix + 1, FileBlock::new(blocks[start_ix].start + blocks[start_ix].size, moving, blocks[end_ix].id));
+        start_ix += 1;
+        end_ix += 1;
+
+        blocks[end_ix].size -= moving;
+        if blocks[end_ix].size == 0 {
+            blocks.pop();
+            end_ix -= 1;
+        }
+    }
+
+    return blocks.iter().map(|b| b.checksum()).sum::<i64>();
+}
+
+pub fn part2(contents: &str) -> i64 {
+    let mut blocks: Vec<FileBlock> = Vec::new();
+    let mut pos: i64 = 0;
+    for (ix, size) in parse(contents).into_iter().enumerate() {
+        if ix % 2 == 0 {
+            blocks.push(FileBlock::new(pos, size, ix as i64 / 2));
+        }
+        pos += size;
+    }
+
+    for block_id in blocks.clone().iter().map(|b| b.id).rev() {
+        let (moving_ix, _) = blocks.iter().enumerate().filter(|(_, b)| b.id == block_id).next().unwrap();
+        let mut moving_block = blocks[moving_ix];
+        if let Some(insert_ix) = blocks.iter().take(moving_ix).enumerate().filter_map(|(ix, b)| {
+            if moving_block.size <= blocks[ix + 1].start - (b.start + b.size) {
+                Some(ix)
+            } else {
+                None
+            }
+        }).next() {
+            moving_block.start = blocks[insert_ix].start + blocks[insert_ix].size;
+            blocks.remove(moving_ix);
+            blocks.insert(insert_ix + 1, moving_block);
+        }
+    }
+
+    return blocks.iter().map(|b| b.checksum()).sum::<i64>();
+}
+
+// Lays out every block and swaps the last file block into the first free one
+fn part1_two_pointer(contents: &str) -> i64 {
+    let mut disk: Vec<Option<i64>> = Vec::new();
+    for (ix, size) in parse(contents).into_iter().enumerate() {
+        let id = if ix % 2 == 0 { Some(ix as i64 / 2) } else { None };
+        disk.extend(std::iter::repeat_n(id, size as usize));
+    }
+
+    let mut start_ix: usize = 0;
+    let mut end_ix: usize = disk.len().saturating_sub(1);
+    while start_ix < end_ix {
+        if disk[start_ix].is_some() {
+            start_ix += 1;
+        } else if disk[end_ix].is_none() {
+            end_ix -= 1;
+        } else {
+            disk.swap(start_ix, end_ix);
+        }
+    }
+
+    return disk.iter().enumerate().filter_map(|(pos, id)| id.map(|id| pos as i64 * id)).sum::<i64>();
+}
+
+// Files only ever move left, so the space they leave behind can never be
+// reused and only the original gaps need tracking
+fn part2_gap_scan(contents: &str) -> i64 {
+    let mut blocks: Vec<FileBlock> = Vec::new();
+    let mut gaps: Vec<(i64, i64)> = Vec::new();
+    let mut pos: i64 = 0;
+    for (ix, size) in parse(contents).into_iter().enumerate() {
+        if ix % 2 == 0 {
+            blocks.push(FileBlock::new(pos, size, ix as i64 / 2));
+        } else {
+            gaps.push((pos, size));
+        }
+        pos += size;
+    }
+
+    for block in blocks.iter_mut().rev() {
+        if let Some(gap) = gaps.iter_mut().take_while(|(start, _)| *start < block.start).find(|(_, size)| *size >= block.size) {
+            block.start = gap.0;
+            gap.0 += block.size;
+            gap.1 -= block.size;
+        }
+    }
+
+    return blocks.iter().map(|b| b.checksum()).sum::<i64>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn p1_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part1(&contents), 1928);
+    }
+
+    #[test]
+    fn p2_test() {
+        let contents =
+            fs::read_to_string("example.txt").expect("Should have been able to read the file");
+
+        assert_eq!(part2(&contents), 2858);
+    }
+
+    #[test]
+    fn variants_test() {
+        let timings = aoc_common::time_variants("2333133121414131402", VARIANTS);
+        assert!(aoc_common::agree(&timings));
+        assert_eq!(timings.iter().map(|t| t.answer.as_str()).collect::<Vec<&str>>(), vec!["1928", "1928", "2858", "2858"]);
+    }
+}
@@ -0,0 +1,112 @@
+use aoc_common::Puzzle;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::solutions::PartResult;
+
+/// One run of a day from the dashboard. Runs are appended to
+/// `answers/history/<year>_<day>.txt`, one tab-separated line each.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Run {
+    pub when: u64,
+    pub params: Vec<(String, String)>,
+    pub outcome: Result<Vec<PartResult>, String>,
+}
+
+pub fn now() -> u64 {
+    return SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+}
+
+/// When `path` was last modified, in seconds since the epoch.
+pub fn modified(path: &Path) -> Option<u64> {
+    let time = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    return time.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs());
+}
+
+fn path(store: &Path, puzzle: &Puzzle) -> PathBuf {
+    return store.join("history").join(puzzle.file_name("txt"));
+}
+
+pub fn format_params(params: &[(String, String)]) -> String {
+    return params.iter().map(|(name, value)| format!("{}={}", name, value)).collect::<Vec<String>>().join(" ");
+}
+
+pub fn parse_params(line: &str) -> Vec<(String, String)> {
+    return line.split_whitespace()
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+}
+
+impl Run {
+    pub fn params_str(&self) -> String {
+        return format_params(&self.params);
+    }
+
+    fn parse(line: &str) -> Option<Run> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let when = fields.first()?.parse::<u64>().ok()?;
+        let params = parse_params(fields.get(1)?);
+        let outcome = match *fields.get(2)? {
+            "FAILED" => Err(fields.get(3).unwrap_or(&"").to_string()),
+            _ => Ok(fields[2..].chunks(2).map(|part| {
+                PartResult { label: "".to_string(), answer: part[0].to_string(), time: part.get(1).unwrap_or(&"").to_string() }
+            }).collect()),
+        };
+
+        return Some(Run { when, params, outcome });
+    }
+}
+
+impl std::fmt::Display for Run {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}\t{}", self.when, self.params_str())?;
+        match &self.outcome {
+            Ok(results) => {
+                for result in results {
+                    write!(f, "\t{}\t{}", result.answer, result.time)?;
+                }
+                Ok(())
+            }
+            // Only the first line, so a panic message can't break the format
+            Err(e) => write!(f, "\tFAILED\t{}", e.trim().lines().next().unwrap_or("")),
+        }
+    }
+}
+
+/// Every recorded run of `puzzle`, oldest first.
+pub fn load(store: &Path, puzzle: &Puzzle) -> Vec<Run> {
+    return match fs::read_to_string(path(store, puzzle)) {
+        Ok(contents) => contents.lines().filter_map(Run::parse).collect(),
+        Err(_) => Vec::new(),
+    };
+}
+
+pub fn append(store: &Path, puzzle: &Puzzle, run: &Run) -> io::Result<()> {
+    let path = path(store, puzzle);
+    fs::create_dir_all(path.parent().unwrap())?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    return writeln!(file, "{}", run);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(answer: &str, time: &str) -> PartResult {
+        return PartResult { label: "".to_string(), answer: answer.to_string(), time: time.to_string() };
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let runs = [
+            Run { when: 1733000000, params: vec![], outcome: Ok(vec![part("11", "1.00000ms"), part("Merry Christmas!", "2.00000ns")]) },
+            Run { when: 1733000060, params: parse_params("blinks=6"), outcome: Err("thread 'main' panicked\nnote: backtrace".to_string()) },
+        ];
+        assert_eq!(Run::parse(&runs[0].to_string()), Some(runs[0].clone()));
+        assert_eq!(runs[1].to_string(), "1733000060\tblinks=6\tFAILED\tthread 'main' panicked");
+        assert_eq!(Run::parse(&runs[1].to_string()).unwrap().outcome, Err("thread 'main' panicked".to_string()));
+    }
+}
@@ -0,0 +1,156 @@
+#![allow(clippy::needless_return)]
+
+use aoc_common::{Answers, Puzzle};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod history;
+mod parity;
+mod readme;
+mod serve;
+mod solutions;
+mod tui;
+mod watch;
+
+const USAGE: &str = "Usage:
+    aoc readme [--year <year>]
+    aoc record <day> [--year <year>] [input]
+    aoc parity [day] [--year <year>] [input]
+    aoc compare <day> [--year <year>] [input]
+    aoc serve [address]
+    aoc tui [--year <year>]
+    aoc watch <day> [--year <year>] [input]";
+
+/// Pulls `--year <year>` out of `args`, defaulting to the latest year solved.
+fn take_year(args: &mut Vec<String>, solved: impl Iterator<Item = Puzzle>) -> u32 {
+    if let Some(ix) = args.iter().position(|arg| arg == "--year") {
+        args.remove(ix);
+        if ix < args.len() {
+            return args.remove(ix).parse::<u32>().expect("Year should be a number");
+        }
+    }
+
+    return solved.map(|puzzle| puzzle.year).max().expect("No solutions found");
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(1);
+}
+
+/// The day named by `arg`, printing the usage if it's missing or not a number.
+fn day(arg: Option<&String>, year: u32) -> Puzzle {
+    return match arg.map(|day| day.parse::<u32>()) {
+        Some(Ok(day)) => Puzzle::new(year, day),
+        _ => usage(),
+    };
+}
+
+fn solution<'a>(solutions: &'a BTreeMap<Puzzle, PathBuf>, puzzle: &Puzzle) -> &'a PathBuf {
+    return solutions.get(puzzle).unwrap_or_else(|| {
+        eprintln!("No solution for {}", puzzle);
+        process::exit(1);
+    });
+}
+
+/// The input named on the command line, or the puzzle's file under `Inputs`.
+fn input_for(puzzle: &Puzzle, arg: Option<&String>, root: &Path) -> PathBuf {
+    return match arg {
+        Some(path) => PathBuf::from(path),
+        None => puzzle.find_input(root).unwrap_or_else(|| {
+            eprintln!("No input found for {}", puzzle);
+            process::exit(1);
+        }),
+    };
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        usage();
+    }
+
+    let command = args.remove(0);
+    let root = solutions::root();
+    let store = solutions::store();
+    let solutions = solutions::discover(&root);
+    let year = take_year(&mut args, solutions.keys().copied());
+
+    match command.as_str() {
+        "readme" => {
+            readme::regenerate(&root, &store, &solutions, year).expect("Should have been able to write the READMEs");
+        }
+        "record" => {
+            let puzzle = day(args.first(), year);
+            let dir = solution(&solutions, &puzzle);
+            let results = solutions::run(dir, args.get(1).map(Path::new)).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(1);
+            });
+
+            let mut answers = Answers::load(&store, &puzzle);
+            for (ix, result) in results.iter().enumerate() {
+                println!("{} part {}: {} ({})", puzzle, ix + 1, result.answer, result.time);
+                answers.record(ix + 1, &result.answer, &result.time);
+            }
+            answers.save(&store, &puzzle).expect("Should have been able to save the answers");
+        }
+        "parity" => {
+            let puzzles: Vec<Puzzle> = match args.first() {
+                Some(_) => vec![day(args.first(), year)],
+                None => solutions.keys().filter(|puzzle| puzzle.year == year).copied().collect(),
+            };
+
+            let mut agree = true;
+            for puzzle in puzzles {
+                let input = input_for(&puzzle, args.get(1), &root);
+                match parity::check(&puzzle, solution(&solutions, &puzzle), &input) {
+                    Ok(same) => agree &= same,
+                    Err(e) => {
+                        println!("{}: FAILED {}", puzzle, e.trim());
+                        agree = false;
+                    }
+                }
+            }
+
+            if !agree {
+                process::exit(1);
+            }
+        }
+        "compare" => {
+            let puzzle = day(args.first(), year);
+            match solutions::compare(solution(&solutions, &puzzle), args.get(1).map(Path::new)) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(e) => {
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        "serve" => {
+            let addr = args.first().map(|a| a.as_str()).unwrap_or("127.0.0.1:8024");
+            if let Err(e) = serve::serve(addr, &solutions, year) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        "tui" => {
+            if let Err(e) = tui::dashboard(&root, &store, &solutions, year) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        "watch" => {
+            let puzzle = day(args.first(), year);
+            let input = input_for(&puzzle, args.get(1), &root);
+            if let Err(e) = watch::watch(&puzzle, solution(&solutions, &puzzle), Some(&input)) {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+        _ => usage(),
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_common::Puzzle;
+use std::path::Path;
+
+use crate::solutions::{self, parse_time, PartResult};
+
+/// How many times longer Python took than Rust on a part, if both times parse.
+fn ratio(rust: &PartResult, python: &PartResult) -> Option<f64> {
+    let rust_time = parse_time(&rust.time)?;
+    let python_time = parse_time(&python.time)?;
+    // Python only prints four decimal places, so fast parts read as zero
+    if rust_time == 0.0 || python_time == 0.0 {
+        return None;
+    }
+
+    return Some(python_time / rust_time);
+}
+
+/// Runs the Rust and Python solutions of `puzzle` on the same `input` and
+/// prints a line per part. Returns whether every part agreed.
+pub fn check(puzzle: &Puzzle, dir: &Path, input: &Path) -> Result<bool, String> {
+    let rust = solutions::run(dir, Some(input))?;
+    let python = solutions::run_python(puzzle, input)?;
+
+    let mut agree = true;
+    for part in 0..rust.len().max(python.len()) {
+        match (rust.get(part), python.get(part)) {
+            (Some(r), Some(p)) => {
+                let status = if r.answer == p.answer { "ok" } else { "MISMATCH" };
+                let speed = ratio(r, p).map(|x| format!(", python/rust {:.1}x", x)).unwrap_or_default();
+                println!(
+                    "{} part {}: {} rust {} ({}) python {} ({}){}",
+                    puzzle, part + 1, status, r.answer, r.time, p.answer, p.time, speed
+                );
+                agree &= r.answer == p.answer;
+            }
+            (r, _) => {
+                println!("{} part {}: MISSING from {}", puzzle, part + 1, if r.is_none() { "rust" } else { "python" });
+                agree = false;
+            }
+        }
+    }
+
+    return Ok(agree);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_test() {
+        let part = |time: &str| PartResult { label: "".to_string(), answer: "1".to_string(), time: time.to_string() };
+        assert_eq!(ratio(&part("2.00000ms"), &part("0.0100 seconds")), Some(5.0));
+        assert_eq!(ratio(&part("0.00000ns"), &part("0.0100 seconds")), None);
+        assert_eq!(ratio(&part("2.00000µs"), &part("0.0000 seconds")), None);
+    }
+}
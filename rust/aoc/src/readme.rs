@@ -0,0 +1,119 @@
+use aoc_common::{Answers, Puzzle};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Replaces everything between `<!-- #{(tag)} -->` and `<!-- #{/(tag)} -->`,
+/// appending a new section if the markers aren't there yet.
+fn replace_section(readme: &str, tag: &str, body: &str) -> String {
+    let open = format!("<!-- #{{({})}} -->", tag);
+    let close = format!("<!-- #{{/({})}} -->", tag);
+    let section = format!("{}\n{}{}", open, body, close);
+
+    return match (readme.find(&open), readme.find(&close)) {
+        (Some(start), Some(end)) if start < end => {
+            format!("{}{}{}", &readme[..start], section, &readme[end + close.len()..])
+        }
+        _ => format!("{}\n{}\n", readme.trim_end(), section),
+    };
+}
+
+/// Rewrites the star count in a shields.io badge labelled `label`.
+fn update_badge(readme: &str, label: &str, stars: u32) -> String {
+    let prefix = format!("badge/{}-", label);
+    let Some(start) = readme.find(&prefix).map(|ix| ix + prefix.len()) else {
+        return readme.to_string();
+    };
+    let Some(len) = readme[start..].find('*') else {
+        return readme.to_string();
+    };
+
+    return format!("{}{}{}", &readme[..start], stars, &readme[start + len..]);
+}
+
+fn tiles(heading: &str, tiles: &[(String, String)]) -> String {
+    let mut body = format!("<h1 align=\"center\">\n  {}\n</h1>\n", heading);
+    for (href, img) in tiles {
+        body += &format!("<a href=\"{}\">\n  <img src=\"{}\" width=\"161px\">\n</a>\n", href, img);
+    }
+
+    return body;
+}
+
+fn star_table(solutions: &[(Puzzle, String, Answers)]) -> String {
+    let mut body = "| Day | Title | Part 1 | Part 2 |\n| --- | --- | --- | --- |\n".to_string();
+    for (puzzle, href, answers) in solutions {
+        let parts: Vec<String> = (0..2).map(|part| {
+            let star = if answers.stars as usize > part { "⭐" } else { "" };
+            match &answers.times[part] {
+                Some(time) => format!("{} {}", star, time).trim().to_string(),
+                None => star.to_string(),
+            }
+        }).collect();
+        body += &format!(
+            "| [{}]({}) | {} | {} | {} |\n",
+            puzzle.day, href, answers.title.as_deref().unwrap_or(""), parts[0], parts[1]
+        );
+    }
+
+    return body;
+}
+
+/// Rebuilds the tiles, badges and star table of `rust/README.md` and the
+/// root README for `year` from the answers store.
+pub fn regenerate(root: &Path, store: &Path, solutions: &BTreeMap<Puzzle, PathBuf>, year: u32) -> io::Result<()> {
+    let solutions: Vec<(Puzzle, String, Answers)> = solutions.iter()
+        .filter(|(puzzle, _)| puzzle.year == year)
+        .map(|(puzzle, dir)| {
+            let rel = dir.strip_prefix(root).unwrap_or(dir).display().to_string();
+            (*puzzle, format!("{}/src/lib.rs", rel), Answers::load(store, puzzle))
+        })
+        .collect();
+    let stars: u32 = solutions.iter().map(|(_, _, answers)| answers.stars).sum();
+
+    let rust_path = root.join("README.md");
+    let mut readme = fs::read_to_string(&rust_path).unwrap_or_default();
+    readme = update_badge(&readme, "Rust", stars);
+    readme = replace_section(&readme, "lang_tiles", &tiles(
+        &format!("{} - {} ⭐ - Rust", year, stars),
+        &solutions.iter().map(|(puzzle, href, _)| {
+            (href.clone(), format!(".tiles/rust/images/{}/{:02}.png", year, puzzle.day))
+        }).collect::<Vec<(String, String)>>(),
+    ));
+    readme = replace_section(&readme, "star_table", &star_table(&solutions));
+    fs::write(&rust_path, readme)?;
+
+    let top_path = root.join("../README.md");
+    let mut readme = fs::read_to_string(&top_path).unwrap_or_default();
+    readme = update_badge(&readme, &year.to_string(), stars);
+    readme = replace_section(&readme, "year_tiles", &tiles(
+        &format!("{} - {} ⭐ - Python/Rust", year, stars),
+        &solutions.iter().map(|(puzzle, _, _)| {
+            (format!("python/{}.py", puzzle.day), format!(".tiles/images/{}/{:02}.png", year, puzzle.day))
+        }).collect::<Vec<(String, String)>>(),
+    ));
+    return fs::write(&top_path, readme);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_test() {
+        let readme = "# Title\n<!-- #{(lang_tiles)} -->\nold\n<!-- #{/(lang_tiles)} -->\n\nFooter\n";
+        let readme = replace_section(readme, "lang_tiles", "new\n");
+        assert_eq!(readme, "# Title\n<!-- #{(lang_tiles)} -->\nnew\n<!-- #{/(lang_tiles)} -->\n\nFooter\n");
+        assert_eq!(
+            replace_section(&readme, "star_table", "table\n"),
+            format!("{}\n<!-- #{{(star_table)}} -->\ntable\n<!-- #{{/(star_table)}} -->\n", readme.trim_end())
+        );
+    }
+
+    #[test]
+    fn badge_test() {
+        let readme = "src=\"https://img.shields.io/badge/Rust-50*-118a03?style=flat-square\"";
+        assert_eq!(update_badge(readme, "Rust", 12), "src=\"https://img.shields.io/badge/Rust-12*-118a03?style=flat-square\"");
+    }
+}
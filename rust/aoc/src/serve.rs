@@ -0,0 +1,174 @@
+use aoc_common::Puzzle;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tiny_http::{Header, Method, Response, Server};
+
+use crate::solutions::{self, explanation, parse_output, parse_time};
+
+static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+fn error(status: u16, message: &str) -> (u16, Value) {
+    return (status, json!({ "error": message }));
+}
+
+// Undoes the percent-encoding of a query string value
+fn decode(value: &str) -> Result<String, String> {
+    let bytes = value.as_bytes();
+    let mut decoded: Vec<u8> = Vec::new();
+    let mut ix = 0;
+    while ix < bytes.len() {
+        match bytes[ix] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                // from_str_radix alone would also take a sign, as in %+1
+                let hex = value.get(ix + 1..ix + 3).filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()));
+                let byte = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                decoded.push(byte.ok_or(format!("Invalid escape in {}", value))?);
+                ix += 2;
+            }
+            byte => decoded.push(byte),
+        }
+        ix += 1;
+    }
+
+    return String::from_utf8(decoded).map_err(|_| format!("Invalid escape in {}", value));
+}
+
+/// A day to solve and the parameters to run it with.
+type SolveRequest = (Puzzle, Vec<(String, String)>);
+
+/// Reads the puzzle out of `/solve/8?year=2024`, or `None` for other routes.
+/// Any other query pairs are parameters for the day, as in `?witness=0`.
+fn parse_solve_url(url: &str, default_year: u32) -> Option<Result<SolveRequest, String>> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let day = path.strip_prefix("/solve/")?;
+
+    let Ok(day) = day.parse::<u32>() else {
+        return Some(Err(format!("Invalid day: {}", day)));
+    };
+    let mut year = default_year;
+    let mut params: Vec<(String, String)> = Vec::new();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let param = match pair.split_once('=') {
+            Some((name, value)) if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => decode(value).map(|value| (name, value)),
+            _ => Err(format!("Invalid parameter: {}", pair)),
+        };
+        match param {
+            Ok(("year", y)) => match y.parse::<u32>() {
+                Ok(y) => year = y,
+                Err(_) => return Some(Err(format!("Invalid year: {}", y))),
+            },
+            Ok((name, value)) => params.push((name.to_string(), value)),
+            Err(e) => return Some(Err(e)),
+        }
+    }
+
+    return Some(Ok((Puzzle::new(year, day), params)));
+}
+
+fn solve(dir: &Path, puzzle: &Puzzle, input: &str, params: &[(String, String)]) -> (u16, Value) {
+    let path = env::temp_dir().join(format!("aoc-serve-{}-{}", REQUESTS.fetch_add(1, Ordering::Relaxed), puzzle.file_name("txt")));
+    if let Err(e) = fs::write(&path, input) {
+        return error(500, &e.to_string());
+    }
+    let stdout = solutions::run_with(dir, Some(&path), params);
+    let _ = fs::remove_file(&path);
+
+    return match stdout {
+        Ok(stdout) => (200, json!({
+            "puzzle": puzzle.to_string(),
+            "year": puzzle.year,
+            "day": puzzle.day,
+            "params": params.iter().cloned().collect::<BTreeMap<String, String>>(),
+            "parts": parse_output(&stdout).iter().enumerate().map(|(ix, result)| json!({
+                "part": ix + 1,
+                "label": result.label,
+                "answer": result.answer,
+                "time": result.time,
+                "seconds": parse_time(&result.time),
+            })).collect::<Vec<Value>>(),
+            "output": explanation(&stdout),
+        })),
+        Err(e) => error(422, e.trim()),
+    };
+}
+
+/// Answers one request, returning the status code and JSON body.
+fn route(method: &Method, url: &str, body: &str, solutions: &BTreeMap<Puzzle, PathBuf>, default_year: u32) -> (u16, Value) {
+    if url == "/solutions" {
+        return (200, json!(solutions.keys().map(|puzzle| puzzle.to_string()).collect::<Vec<String>>()));
+    }
+
+    let (puzzle, params) = match parse_solve_url(url, default_year) {
+        None => return error(404, &format!("No route for {}", url)),
+        Some(_) if *method != Method::Post => return error(405, "Use POST with the puzzle input as the body"),
+        Some(Err(e)) => return error(400, &e),
+        Some(Ok(request)) => request,
+    };
+
+    return match solutions.get(&puzzle) {
+        Some(dir) => solve(dir, &puzzle, body, &params),
+        None => error(404, &format!("No solution for {}", puzzle)),
+    };
+}
+
+/// Serves `POST /solve/{day}[?year=<year>&<param>=<value>...]`, which runs a
+/// day's solution on the request body with any parameters overridden and
+/// returns its answers, timings and whatever else it printed, and
+/// `GET /solutions`, which lists what can be solved.
+pub fn serve(addr: &str, solutions: &BTreeMap<Puzzle, PathBuf>, default_year: u32) -> Result<(), String> {
+    let server = Server::http(addr).map_err(|e| e.to_string())?;
+    println!("Listening on http://{}", addr);
+
+    for mut request in server.incoming_requests() {
+        let mut body = String::new();
+        let (status, value) = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => route(request.method(), request.url(), &body, solutions, default_year),
+            Err(e) => error(400, &e.to_string()),
+        };
+        println!("{} {} -> {}", request.method(), request.url(), status);
+
+        let response = Response::from_string(value.to_string())
+            .with_status_code(status)
+            .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+        if let Err(e) = request.respond(response) {
+            eprintln!("Couldn't respond: {}", e);
+        }
+    }
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_solve_url_test() {
+        assert_eq!(parse_solve_url("/solve/8", 2024), Some(Ok((Puzzle::new(2024, 8), vec![]))));
+        assert_eq!(parse_solve_url("/solve/8?year=2025", 2024), Some(Ok((Puzzle::new(2025, 8), vec![]))));
+        assert_eq!(
+            parse_solve_url("/solve/6?export=map&toggles=1%2C2&year=2025", 2024),
+            Some(Ok((Puzzle::new(2025, 6), vec![("export".to_string(), "map".to_string()), ("toggles".to_string(), "1,2".to_string())]))),
+        );
+        assert_eq!(parse_solve_url("/solve/x", 2024), Some(Err("Invalid day: x".to_string())));
+        assert_eq!(parse_solve_url("/solve/6?export", 2024), Some(Err("Invalid parameter: export".to_string())));
+        assert_eq!(parse_solve_url("/solve/6?a-b=1", 2024), Some(Err("Invalid parameter: a-b=1".to_string())));
+        assert_eq!(parse_solve_url("/solve/6?toggles=%2", 2024), Some(Err("Invalid escape in %2".to_string())));
+        assert_eq!(parse_solve_url("/solve/6?toggles=%+1", 2024), Some(Err("Invalid escape in %+1".to_string())));
+        assert_eq!(parse_solve_url("/other", 2024), None);
+    }
+
+    #[test]
+    fn route_errors_test() {
+        let solutions = BTreeMap::from([(Puzzle::new(2024, 1), PathBuf::from("1"))]);
+        assert_eq!(route(&Method::Post, "/solve/2", "", &solutions, 2024).0, 404);
+        assert_eq!(route(&Method::Get, "/solve/1", "", &solutions, 2024).0, 405);
+        assert_eq!(route(&Method::Post, "/solve/1?year=abc", "", &solutions, 2024).0, 400);
+        assert_eq!(route(&Method::Get, "/solutions", "", &solutions, 2024), (200, json!(["2024_1"])));
+    }
+}
@@ -0,0 +1,198 @@
+use aoc_common::Puzzle;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The directory holding every per-day crate (the parent of this one).
+pub fn root() -> PathBuf {
+    return Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf();
+}
+
+/// Where verified answers are kept, see `aoc_common::Answers`.
+pub fn store() -> PathBuf {
+    return root().join("answers");
+}
+
+fn package_name(manifest: &Path) -> Option<String> {
+    let contents = fs::read_to_string(manifest).ok()?;
+    return contents.lines()
+        .find_map(|line| line.strip_prefix("name = "))
+        .map(|name| name.trim_matches('"').to_string());
+}
+
+/// Finds every `rust_<year>_<day>` crate up to two levels below `root`, so both
+/// the `rust/<day>` and `rust/<year>/<day>` layouts are picked up.
+pub fn discover(root: &Path) -> BTreeMap<Puzzle, PathBuf> {
+    let mut solutions: BTreeMap<Puzzle, PathBuf> = BTreeMap::new();
+    let mut dirs: Vec<PathBuf> = vec![root.to_path_buf()];
+    for depth in 0..2 {
+        let mut next_dirs: Vec<PathBuf> = Vec::new();
+        for dir in dirs {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten().filter(|e| e.path().is_dir()) {
+                let path = entry.path();
+                match package_name(&path.join("Cargo.toml")) {
+                    Some(name) if name.starts_with("rust_") => {
+                        solutions.insert(Puzzle::from_package(&name), path);
+                    }
+                    _ if depth == 0 => next_dirs.push(path),
+                    _ => {}
+                }
+            }
+        }
+        dirs = next_dirs;
+    }
+
+    return solutions;
+}
+
+/// One part's answer as printed by a day's `main`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub label: String,
+    pub answer: String,
+    pub time: String,
+}
+
+/// Parses the `Part N:` / `<label>: <answer>` / `Ran in <time>` blocks every
+/// day prints.
+pub fn parse_output(stdout: &str) -> Vec<PartResult> {
+    let lines: Vec<&str> = stdout.lines().collect();
+    return lines.iter().enumerate().filter_map(|(ix, line)| {
+        if !(line.starts_with("Part ") && line.ends_with(':')) || ix + 2 >= lines.len() {
+            return None;
+        }
+        let (label, answer) = lines[ix + 1].split_once(": ").unwrap_or(("", lines[ix + 1]));
+        let time = lines[ix + 2].strip_prefix("Ran in ")?;
+        Some(PartResult { label: label.to_string(), answer: answer.to_string(), time: time.to_string() })
+    }).collect();
+}
+
+/// Everything else a day printed, such as a witness or an explanation asked
+/// for through its parameters.
+pub fn explanation(stdout: &str) -> String {
+    let lines: Vec<&str> = stdout.lines().collect();
+    let mut keep = vec![true; lines.len()];
+    for (ix, line) in lines.iter().enumerate() {
+        if line.starts_with("Part ") && line.ends_with(':') && ix + 2 < lines.len() && lines[ix + 2].starts_with("Ran in ") {
+            keep[ix..=ix + 2].fill(false);
+        }
+    }
+
+    return lines.iter().zip(keep).filter(|(_, keep)| *keep).map(|(line, _)| *line).collect::<Vec<&str>>().join("\n").trim().to_string();
+}
+
+/// Converts a printed run time to seconds. Rust prints `Duration`s like
+/// `12.50000µs`, Python prints `0.0125 seconds`.
+pub fn parse_time(time: &str) -> Option<f64> {
+    let time = time.trim();
+    let split = time.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+    let value = time[..split].parse::<f64>().ok()?;
+    let scale = match time[split..].trim() {
+        "ns" => 1e-9,
+        "µs" | "us" => 1e-6,
+        "ms" => 1e-3,
+        "s" | "seconds" => 1.0,
+        _ => return None,
+    };
+
+    return Some(value * scale);
+}
+
+fn stdout(command: &mut Command) -> Result<String, String> {
+    let output = command.output().map_err(|e| format!("Couldn't run {:?}: {}", command.get_program(), e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+
+    return Ok(String::from_utf8_lossy(&output.stdout).to_string());
+}
+
+fn capture(command: &mut Command) -> Result<Vec<PartResult>, String> {
+    return stdout(command).map(|stdout| parse_output(&stdout));
+}
+
+/// Builds and runs the solution in `dir` in release mode.
+pub fn run(dir: &Path, input: Option<&Path>) -> Result<Vec<PartResult>, String> {
+    return run_with(dir, input, &[]).map(|stdout| parse_output(&stdout));
+}
+
+/// Like `run`, but overrides the day's tunable constants (see
+/// `aoc_common::Puzzle::param`) and returns everything it printed.
+pub fn run_with(dir: &Path, input: Option<&Path>, params: &[(String, String)]) -> Result<String, String> {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--release", "--quiet", "--"]).current_dir(dir);
+    if let Some(input) = input {
+        command.arg(input);
+    }
+    for (name, value) in params {
+        command.env(format!("AOC_PARAM_{}", name.to_uppercase()), value);
+    }
+
+    return stdout(&mut command);
+}
+
+/// Runs the solution in `dir` with `AOC_VARIANTS` set, so it times each of
+/// its registered variants and prints its own table. Returns whether they agreed.
+pub fn compare(dir: &Path, input: Option<&Path>) -> Result<bool, String> {
+    let mut command = Command::new("cargo");
+    command.args(["run", "--release", "--quiet", "--"]).current_dir(dir).env("AOC_VARIANTS", "1");
+    if let Some(input) = input {
+        command.arg(input);
+    }
+
+    let status = command.status().map_err(|e| format!("Couldn't run cargo in {}: {}", dir.display(), e))?;
+    return Ok(status.success());
+}
+
+/// Runs `python/<day>.py` with `AOC_PYTHON` (default `python3`).
+pub fn run_python(puzzle: &Puzzle, input: &Path) -> Result<Vec<PartResult>, String> {
+    let repo = root().parent().unwrap().to_path_buf();
+    let interpreter = env::var("AOC_PYTHON").unwrap_or("python3".to_string());
+    let script = repo.join("python").join(format!("{}.py", puzzle.day));
+
+    return capture(Command::new(interpreter).arg(script).arg(input).current_dir(repo));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_output_test() {
+        let stdout = "\nPart 1:\nDistance: 11\nRan in 12.50000µs\n\nPart 2:\nMerry Christmas!\nRan in 100.00000ns\n";
+        assert_eq!(parse_output(stdout), vec![
+            PartResult { label: "Distance".to_string(), answer: "11".to_string(), time: "12.50000µs".to_string() },
+            PartResult { label: "".to_string(), answer: "Merry Christmas!".to_string(), time: "100.00000ns".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn explanation_test() {
+        let stdout = "\nPart 1:\nDistance: 11\nRan in 12.50000µs\n\nPart 2:\nScore: 31\nRan in 1.00000ms\n\n.O.\n^..\n";
+        assert_eq!(explanation(stdout), ".O.\n^..");
+        assert_eq!(explanation("\nPart 1:\nDistance: 11\nRan in 12.50000µs\n"), "");
+    }
+
+    #[test]
+    fn parse_time_test() {
+        assert_eq!(parse_time("12.50000ms"), Some(0.0125));
+        assert_eq!(parse_time("1.50000s"), Some(1.5));
+        assert_eq!(parse_time("0.2500 seconds"), Some(0.25));
+        assert_eq!(parse_time("soon"), None);
+    }
+
+    #[test]
+    fn discover_test() {
+        let solutions = discover(&root());
+        assert_eq!(solutions.get(&Puzzle::new(2024, 5)), Some(&root().join("5")));
+        // Only day crates count, not the runner or shared libraries
+        assert!(solutions.keys().all(|puzzle| (1..=25).contains(&puzzle.day)));
+        assert!(solutions.values().all(|dir| dir.join("src").join("lib.rs").is_file() && dir.join("src").join("main.rs").is_file()));
+        assert!(!solutions.values().any(|dir| dir.ends_with("aoc") || dir.ends_with("common")));
+    }
+}
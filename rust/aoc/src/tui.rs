@@ -0,0 +1,468 @@
+use aoc_common::{Answers, Puzzle};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use crate::history::{self, Run};
+use crate::solutions::{self, explanation, parse_output, parse_time, PartResult};
+
+/// The parameters that get a day to print a witness or explanation of its
+/// answers after them.
+const WITNESSES: &[(u32, &[(&str, &str)])] = &[
+    (1, &[("top", "5")]),
+    (2, &[("report", "table")]),
+    (4, &[("show_matches", "true")]),
+    (5, &[("show_violations", "true"), ("show_moves", "true")]),
+    (6, &[("export", "map"), ("witness", "0")]),
+];
+
+/// A day's solution and the binary that runs it, under its `src`.
+const SOURCES: [&str; 2] = ["lib.rs", "main.rs"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Status {
+    Unsolved,
+    Solved,
+    Verified,
+    Failing,
+    Stale,
+}
+
+/// How a day stands. Its last run with the default parameters is checked
+/// against the recorded answers, unless the source has changed since then
+/// (or since the answers were recorded, if it hasn't been run here).
+pub fn status(answers: &Answers, last: Option<&Run>, recorded: Option<u64>, changed: Option<u64>) -> Status {
+    if answers.stars == 0 && last.is_none() {
+        return Status::Unsolved;
+    }
+    if let (Some(checked), Some(changed)) = (last.map(|run| run.when).or(recorded), changed) {
+        if changed > checked {
+            return Status::Stale;
+        }
+    }
+
+    return match last.map(|run| &run.outcome) {
+        None => Status::Solved,
+        Some(Err(_)) => Status::Failing,
+        Some(Ok(results)) => {
+            let expected: Vec<(usize, &String)> = answers.answers.iter().enumerate()
+                .filter_map(|(ix, answer)| answer.as_ref().map(|a| (ix, a)))
+                .collect();
+            if !expected.iter().all(|(ix, answer)| results.get(*ix).is_some_and(|r| r.answer == **answer)) {
+                Status::Failing
+            } else if expected.is_empty() {
+                Status::Solved
+            } else {
+                Status::Verified
+            }
+        }
+    };
+}
+
+/// The tunable constants a day reads through `Puzzle::param`, with their
+/// defaults, found by scanning its source for `.param("<name>", <default>)`.
+/// A default naming a `const` is resolved to its value.
+pub fn params(source: &str) -> Vec<(String, String)> {
+    let mut found: Vec<(String, String)> = Vec::new();
+    for piece in source.split(".param(\"").skip(1) {
+        let Some((name, rest)) = piece.split_once('"') else {
+            continue;
+        };
+        let default = rest.trim_start_matches([',', ' ']).split(')').next().unwrap().trim();
+        let default = source.lines()
+            .find_map(|line| line.trim().strip_prefix(&format!("const {}:", default)))
+            .and_then(|decl| decl.split_once('='))
+            .map(|(_, value)| value.trim().trim_end_matches(';').to_string())
+            .unwrap_or(default.to_string());
+        if !found.iter().any(|(n, _)| n == name) {
+            found.push((name.to_string(), default));
+        }
+    }
+
+    return found;
+}
+
+/// `day`'s witness parameters along with `overrides`, which win where both
+/// set one, or `None` if it has none.
+pub fn witness_params(day: u32, overrides: &[(String, String)]) -> Option<Vec<(String, String)>> {
+    let (_, witness) = WITNESSES.iter().find(|(d, _)| *d == day)?;
+    let mut params = overrides.to_vec();
+    for (name, value) in witness.iter().filter(|(name, _)| !overrides.iter().any(|(n, _)| n == name)) {
+        params.push((name.to_string(), value.to_string()));
+    }
+    return Some(params);
+}
+
+fn age(secs: u64) -> String {
+    return match secs {
+        0..60 => format!("{}s", secs),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    };
+}
+
+fn total_time(results: &[PartResult]) -> String {
+    let times: Option<Vec<f64>> = results.iter().map(|r| parse_time(&r.time)).collect();
+    return match times {
+        Some(times) if !times.is_empty() => format!("{:.2?}", Duration::from_secs_f64(times.iter().sum())),
+        _ => "".to_string(),
+    };
+}
+
+struct Day {
+    puzzle: Puzzle,
+    dir: PathBuf,
+    input: Option<PathBuf>,
+    answers: Answers,
+    recorded: Option<u64>,
+    defaults: Vec<(String, String)>,
+    params: Vec<(String, String)>,
+    history: Vec<Run>,
+    output: String,
+    witness: String,
+}
+
+impl Day {
+    /// The parameters that differ from the day's defaults.
+    fn overrides(&self) -> Vec<(String, String)> {
+        return self.params.iter().filter(|p| !self.defaults.contains(p)).cloned().collect();
+    }
+
+    fn status(&self) -> Status {
+        let last = self.history.iter().rev().find(|run| run.params.is_empty());
+        let modified = SOURCES.iter().filter_map(|file| history::modified(&self.dir.join("src").join(file))).max();
+        return status(&self.answers, last, self.recorded, modified);
+    }
+
+    /// Answers from the latest run, falling back to the recorded ones.
+    fn latest(&self) -> Vec<PartResult> {
+        if let Some(Ok(results)) = self.history.last().map(|run| &run.outcome) {
+            return results.clone();
+        }
+        return (0..2).map(|ix| PartResult {
+            label: "".to_string(),
+            answer: self.answers.answers[ix].clone().unwrap_or_default(),
+            time: self.answers.times[ix].clone().unwrap_or_default(),
+        }).collect();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Pane {
+    Output,
+    History,
+    Witness,
+}
+
+struct App {
+    year: u32,
+    store: PathBuf,
+    days: Vec<Day>,
+    table: TableState,
+    pane: Pane,
+    editing: Option<String>,
+    running: Vec<usize>,
+    sender: Sender<(usize, Run, String, bool)>,
+    receiver: Receiver<(usize, Run, String, bool)>,
+}
+
+impl App {
+    fn new(root: &Path, store: &Path, solutions: &BTreeMap<Puzzle, PathBuf>, year: u32) -> App {
+        let days = solutions.iter().filter(|(puzzle, _)| puzzle.year == year).map(|(puzzle, dir)| {
+            let source = Vec::from_iter(SOURCES.iter().map(|file| fs::read_to_string(dir.join("src").join(file)).unwrap_or_default()));
+            let defaults = params(&source.join("\n"));
+            Day {
+                puzzle: *puzzle,
+                dir: dir.clone(),
+                input: puzzle.find_input(root),
+                answers: Answers::load(store, puzzle),
+                recorded: history::modified(&Answers::path(store, puzzle)),
+                params: defaults.clone(),
+                defaults,
+                history: history::load(store, puzzle),
+                output: "".to_string(),
+                witness: "".to_string(),
+            }
+        }).collect();
+
+        let (sender, receiver) = mpsc::channel();
+        return App {
+            year,
+            store: store.to_path_buf(),
+            days,
+            table: TableState::default().with_selected(Some(0)),
+            pane: Pane::Output,
+            editing: None,
+            running: Vec::new(),
+            sender,
+            receiver,
+        };
+    }
+
+    fn selected(&self) -> usize {
+        return self.table.selected().unwrap_or(0).min(self.days.len().saturating_sub(1));
+    }
+
+    /// Runs the selected day in the background with its current parameters,
+    /// plus its witness parameters if `witness` is set.
+    fn start(&mut self, witness: bool) {
+        let ix = self.selected();
+        if self.running.contains(&ix) {
+            return;
+        }
+
+        let day = &self.days[ix];
+        let params = match witness {
+            false => day.overrides(),
+            true => match witness_params(day.puzzle.day, &day.overrides()) {
+                Some(params) => params,
+                None => return,
+            },
+        };
+        let (dir, input, sender) = (day.dir.clone(), day.input.clone(), self.sender.clone());
+        self.running.push(ix);
+        thread::spawn(move || {
+            let result = solutions::run_with(&dir, input.as_deref(), &params);
+            let run = Run {
+                when: history::now(),
+                params,
+                outcome: result.as_ref().map(|stdout| parse_output(stdout)).map_err(|e| e.clone()),
+            };
+            let _ = sender.send((ix, run, result.unwrap_or_else(|e| e), witness));
+        });
+    }
+
+    fn finish(&mut self, ix: usize, run: Run, output: String, witness: bool) {
+        self.running.retain(|r| *r != ix);
+        let day = &mut self.days[ix];
+        if witness {
+            day.witness = match &run.outcome {
+                Ok(_) => explanation(&output),
+                Err(_) => output.clone(),
+            };
+        }
+        day.output = match history::append(&self.store, &day.puzzle, &run) {
+            Ok(()) => output,
+            Err(e) => format!("{}\n(Couldn't save to history: {})", output, e),
+        };
+        day.history.push(run);
+    }
+
+    /// Handles a key press, returning whether to keep going.
+    fn key(&mut self, code: KeyCode) -> bool {
+        let ix = self.selected();
+        if let Some(buffer) = &mut self.editing {
+            match code {
+                KeyCode::Char(c) => buffer.push(c),
+                KeyCode::Backspace => {
+                    buffer.pop();
+                }
+                KeyCode::Enter => {
+                    for (name, value) in history::parse_params(buffer) {
+                        if let Some(param) = self.days[ix].params.iter_mut().find(|(n, _)| *n == name) {
+                            param.1 = value;
+                        }
+                    }
+                    self.editing = None;
+                }
+                KeyCode::Esc => self.editing = None,
+                _ => {}
+            }
+            return true;
+        }
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Down | KeyCode::Char('j') => self.table.select(Some((ix + 1).min(self.days.len() - 1))),
+            KeyCode::Up | KeyCode::Char('k') => self.table.select(Some(ix.saturating_sub(1))),
+            KeyCode::Enter | KeyCode::Char('r') => self.start(false),
+            KeyCode::Char('w') => {
+                self.start(true);
+                self.pane = Pane::Witness;
+            }
+            KeyCode::Char('h') => self.pane = if self.pane == Pane::History { Pane::Output } else { Pane::History },
+            KeyCode::Char('p') if !self.days[ix].params.is_empty() => {
+                self.editing = Some(history::format_params(&self.days[ix].params));
+            }
+            KeyCode::Char('d') => self.days[ix].params = self.days[ix].defaults.clone(),
+            _ => {}
+        }
+        return true;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] = Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+        let [list, detail] = Layout::horizontal([Constraint::Percentage(60), Constraint::Fill(1)]).areas(main);
+
+        let rows: Vec<Row> = self.days.iter().enumerate().map(|(ix, day)| {
+            let (text, color) = match day.status() {
+                _ if self.running.contains(&ix) => ("running", Color::Cyan),
+                Status::Unsolved => ("unsolved", Color::DarkGray),
+                Status::Solved => ("solved", Color::White),
+                Status::Verified => ("verified", Color::Green),
+                Status::Failing => ("failing", Color::Red),
+                Status::Stale => ("stale", Color::Yellow),
+            };
+            let latest = day.latest();
+            Row::new(vec![
+                Cell::from(day.puzzle.day.to_string()),
+                Cell::from(day.answers.title.clone().unwrap_or_default()),
+                Cell::from(text).style(Style::new().fg(color)),
+                Cell::from(latest.first().map(|r| r.answer.clone()).unwrap_or_default()),
+                Cell::from(latest.get(1).map(|r| r.answer.clone()).unwrap_or_default()),
+                Cell::from(total_time(&latest)),
+            ])
+        }).collect();
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Fill(1),
+            Constraint::Length(8),
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(10),
+        ];
+        let table = Table::new(rows, widths)
+            .header(Row::new(["Day", "Title", "Status", "Part 1", "Part 2", "Time"]).style(Style::new().add_modifier(Modifier::BOLD)))
+            .block(Block::bordered().title(format!(" Advent of Code {} ", self.year)))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, list, &mut self.table);
+
+        let day = &self.days[self.selected()];
+        let [params, body] = Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(detail);
+        let params_text = match &self.editing {
+            Some(buffer) => format!("{}_", buffer),
+            None if day.params.is_empty() => "none".to_string(),
+            None => history::format_params(&day.params),
+        };
+        frame.render_widget(Paragraph::new(params_text).block(Block::bordered().title(" Parameters ")), params);
+
+        let title = format!(" {}: {} ", day.puzzle, day.answers.title.clone().unwrap_or_default());
+        let lines: Vec<Line> = if self.pane == Pane::History {
+            let now = history::now();
+            day.history.iter().rev().map(|run| {
+                let outcome = match &run.outcome {
+                    Ok(results) => results.iter().map(|r| format!("{} ({})", r.answer, r.time)).collect::<Vec<String>>().join(", "),
+                    Err(e) => format!("FAILED {}", e),
+                };
+                Line::from(format!("{:>4} ago  {}  {}", age(now.saturating_sub(run.when)), run.params_str(), outcome))
+            }).collect()
+        } else if self.pane == Pane::Witness && witness_params(day.puzzle.day, &[]).is_none() {
+            vec![Line::from("This day doesn't print a witness.")]
+        } else if self.pane == Pane::Witness && day.witness.is_empty() {
+            vec![Line::from("No witness yet, press w to run it with its witness parameters.")]
+        } else if self.pane == Pane::Witness {
+            day.witness.lines().map(Line::from).collect()
+        } else if day.output.is_empty() {
+            vec![Line::from("Not run yet, press r to run it.")]
+        } else {
+            day.output.lines().map(Line::from).collect()
+        };
+        let body_title = match self.pane {
+            Pane::Output => "Output",
+            Pane::History => "History",
+            Pane::Witness => "Witness",
+        };
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: false }).block(Block::bordered().title(format!("{}- {} ", title, body_title))),
+            body,
+        );
+
+        let keys = if self.editing.is_some() {
+            "Edit name=value pairs  enter apply  esc cancel"
+        } else {
+            "↑/↓ select  r run  w witness  p parameters  d defaults  h history/output  q quit"
+        };
+        frame.render_widget(Paragraph::new(keys).style(Style::new().fg(Color::DarkGray)), help);
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            while let Ok((ix, run, output, witness)) = self.receiver.try_recv() {
+                self.finish(ix, run, output, witness);
+            }
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if !event::poll(Duration::from_millis(100))? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !self.key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// An interactive dashboard of every day in `year`: whether it's solved,
+/// verified, failing or stale, its latest answers and timings, the output
+/// and history of running it with tweaked parameters, and the witness it
+/// prints when run with its witness parameters.
+pub fn dashboard(root: &Path, store: &Path, solutions: &BTreeMap<Puzzle, PathBuf>, year: u32) -> io::Result<()> {
+    let mut app = App::new(root, store, solutions, year);
+    if app.days.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, format!("No solutions found for {}", year)));
+    }
+
+    let mut terminal = ratatui::init();
+    let result = app.event_loop(&mut terminal);
+    ratatui::restore();
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(when: u64, outcome: Result<&[&str], &str>) -> Run {
+        let outcome = outcome.map(|answers| answers.iter().map(|a| {
+            PartResult { label: "".to_string(), answer: a.to_string(), time: "1.00000ms".to_string() }
+        }).collect()).map_err(|e| e.to_string());
+        return Run { when, params: vec![], outcome };
+    }
+
+    #[test]
+    fn status_test() {
+        let answers = Answers::parse("stars: 2\npart1: 11\npart2: 31\n");
+        assert_eq!(status(&Answers::default(), None, None, Some(10)), Status::Unsolved);
+        assert_eq!(status(&answers, None, Some(20), Some(10)), Status::Solved);
+        assert_eq!(status(&answers, None, Some(5), Some(10)), Status::Stale);
+        assert_eq!(status(&answers, Some(&run(20, Ok(&["11", "31"]))), Some(5), Some(10)), Status::Verified);
+        assert_eq!(status(&answers, Some(&run(20, Ok(&["11", "32"]))), Some(5), Some(10)), Status::Failing);
+        assert_eq!(status(&answers, Some(&run(20, Ok(&["11"]))), Some(5), Some(10)), Status::Failing);
+        assert_eq!(status(&answers, Some(&run(20, Err("panicked"))), Some(5), Some(10)), Status::Failing);
+        assert_eq!(status(&answers, Some(&run(8, Ok(&["11", "31"]))), Some(5), Some(10)), Status::Stale);
+    }
+
+    #[test]
+    fn witness_params_test() {
+        let overrides = vec![("witness".to_string(), "3".to_string()), ("turn".to_string(), "left".to_string())];
+        assert_eq!(witness_params(6, &overrides), Some(vec![
+            ("witness".to_string(), "3".to_string()),
+            ("turn".to_string(), "left".to_string()),
+            ("export".to_string(), "map".to_string()),
+        ]));
+        assert_eq!(witness_params(9, &overrides), None);
+    }
+
+    #[test]
+    fn params_test() {
+        let source = "const WIDTH: i64 = 101;\nfn main() {\n    part1(&contents, puzzle.param(\"width\", WIDTH), puzzle.param(\"blinks\", 25));\n    part2(&contents, puzzle.param(\"width\", WIDTH));\n}";
+        assert_eq!(params(source), vec![
+            ("width".to_string(), "101".to_string()),
+            ("blinks".to_string(), "25".to_string()),
+        ]);
+        assert_eq!(params("fn main() {}"), vec![]);
+    }
+}
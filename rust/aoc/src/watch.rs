@@ -0,0 +1,128 @@
+use aoc_common::Puzzle;
+use inotify::{Inotify, WatchDescriptor, WatchMask};
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+use crate::solutions::{self, parse_time, PartResult};
+
+/// Describes how each part changed since the previous run.
+fn diff(previous: Option<&[PartResult]>, current: &[PartResult]) -> Vec<String> {
+    return current.iter().enumerate().map(|(ix, result)| {
+        let Some(before) = previous.and_then(|p| p.get(ix)) else {
+            return format!("part {}: {} ({})", ix + 1, result.answer, result.time);
+        };
+
+        let answer = if before.answer == result.answer {
+            result.answer.clone()
+        } else {
+            format!("{} -> {} CHANGED", before.answer, result.answer)
+        };
+        let time = match (parse_time(&before.time), parse_time(&result.time)) {
+            (Some(b), Some(a)) if b > 0.0 => format!("{} -> {}, {:+.0}%", before.time, result.time, (a - b) / b * 100.0),
+            _ => format!("{} -> {}", before.time, result.time),
+        };
+
+        format!("part {}: {} ({})", ix + 1, answer, time)
+    }).collect();
+}
+
+/// Whether a change to `name` in a watched directory should trigger a rerun.
+fn relevant(name: &OsStr, input: Option<&OsStr>) -> bool {
+    let name = name.to_string_lossy();
+    return name.ends_with(".rs") || name == "Cargo.toml" || name.starts_with("example")
+        || input.is_some_and(|input| input.to_string_lossy() == name);
+}
+
+fn rerun(dir: &Path, input: Option<&Path>, previous: Option<&[PartResult]>) -> Option<Vec<PartResult>> {
+    match Command::new("cargo").args(["test", "--release", "--quiet"]).current_dir(dir).output() {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let failed: Vec<&str> = stdout.lines().filter(|line| line.ends_with("FAILED")).collect();
+            match stdout.lines().find(|line| line.starts_with("test result")) {
+                Some(summary) => println!("{}", summary),
+                None => println!("tests didn't build:\n{}", String::from_utf8_lossy(&output.stderr).trim()),
+            }
+            for line in failed {
+                println!("  {}", line);
+            }
+        }
+        Err(e) => println!("Couldn't run tests: {}", e),
+    }
+
+    return match solutions::run(dir, input) {
+        Ok(results) => {
+            for line in diff(previous, &results) {
+                println!("{}", line);
+            }
+            Some(results)
+        }
+        Err(e) => {
+            println!("Run failed:\n{}", e.trim());
+            None
+        }
+    };
+}
+
+/// Reruns the tests and the real input for `puzzle` whenever its sources,
+/// manifest, fixtures or input change.
+pub fn watch(puzzle: &Puzzle, dir: &Path, input: Option<&Path>) -> Result<(), String> {
+    let mut inotify = Inotify::init().map_err(|e| e.to_string())?;
+    let mask = WatchMask::CLOSE_WRITE | WatchMask::CREATE | WatchMask::DELETE | WatchMask::MOVED_TO;
+    let mut watched: Vec<WatchDescriptor> = Vec::new();
+    for path in [Some(dir.join("src")), Some(dir.to_path_buf()), input.and_then(|i| i.parent()).map(|p| p.to_path_buf())].into_iter().flatten() {
+        watched.push(inotify.watches().add(&path, mask).map_err(|e| format!("Couldn't watch {}: {}", path.display(), e))?);
+    }
+
+    let mut previous: Option<Vec<PartResult>> = None;
+    let mut buffer = [0; 4096];
+    loop {
+        println!("\n== {} ==", puzzle);
+        if let Some(results) = rerun(dir, input, previous.as_deref()) {
+            previous = Some(results);
+        }
+
+        loop {
+            let events = inotify.read_events_blocking(&mut buffer).map_err(|e| e.to_string())?;
+            if events.filter(|e| watched.contains(&e.wd)).any(|e| e.name.is_some_and(|n| relevant(n, input.and_then(|i| i.file_name())))) {
+                break;
+            }
+        }
+
+        // Editors tend to write several files at once, so let them settle
+        thread::sleep(Duration::from_millis(200));
+        while inotify.read_events(&mut buffer).is_ok_and(|mut events| events.next().is_some()) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(answer: &str, time: &str) -> PartResult {
+        return PartResult { label: "".to_string(), answer: answer.to_string(), time: time.to_string() };
+    }
+
+    #[test]
+    fn diff_test() {
+        let before = vec![part("11", "2.00000ms"), part("31", "1.00000ms")];
+        let after = vec![part("11", "1.00000ms"), part("32", "1.00000ms")];
+        assert_eq!(diff(None, &after)[0], "part 1: 11 (1.00000ms)");
+        assert_eq!(diff(Some(&before), &after), vec![
+            "part 1: 11 (2.00000ms -> 1.00000ms, -50%)",
+            "part 2: 31 -> 32 CHANGED (1.00000ms -> 1.00000ms, +0%)",
+        ]);
+    }
+
+    #[test]
+    fn relevant_test() {
+        let input = Some(OsStr::new("2024_1.txt"));
+        assert!(relevant(OsStr::new("main.rs"), input));
+        assert!(relevant(OsStr::new("example.txt"), input));
+        assert!(relevant(OsStr::new("2024_1.txt"), input));
+        assert!(!relevant(OsStr::new("2024_2.txt"), input));
+        assert!(!relevant(OsStr::new(".main.rs.swp~"), None));
+    }
+}
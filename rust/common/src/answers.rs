@@ -0,0 +1,95 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Puzzle;
+
+/// A puzzle's entry in the verified-answers store. Each puzzle gets its own
+/// `answers/<year>_<day>.txt` file holding `key: value` lines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    pub title: Option<String>,
+    pub stars: u32,
+    pub answers: [Option<String>; 2],
+    pub times: [Option<String>; 2],
+}
+
+impl Answers {
+    pub fn path(store: &Path, puzzle: &Puzzle) -> PathBuf {
+        return store.join(puzzle.file_name("txt"));
+    }
+
+    /// Loads the entry for `puzzle`, or an empty entry if it has none yet.
+    pub fn load(store: &Path, puzzle: &Puzzle) -> Answers {
+        return match fs::read_to_string(Answers::path(store, puzzle)) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(_) => Answers::default(),
+        };
+    }
+
+    pub fn save(&self, store: &Path, puzzle: &Puzzle) -> io::Result<()> {
+        fs::create_dir_all(store)?;
+        return fs::write(Answers::path(store, puzzle), self.to_string());
+    }
+
+    pub fn parse(contents: &str) -> Answers {
+        let mut entry = Answers::default();
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once(": ") else {
+                continue;
+            };
+            let value = value.trim().to_string();
+            match key.trim() {
+                "title" => entry.title = Some(value),
+                "stars" => entry.stars = value.parse::<u32>().unwrap_or(0),
+                "part1" => entry.answers[0] = Some(value),
+                "part2" => entry.answers[1] = Some(value),
+                "part1_time" => entry.times[0] = Some(value),
+                "part2_time" => entry.times[1] = Some(value),
+                _ => {}
+            }
+        }
+
+        return entry;
+    }
+
+    /// Stores a freshly verified answer, earning its star if it hadn't been.
+    pub fn record(&mut self, part: usize, answer: &str, time: &str) {
+        self.answers[part - 1] = Some(answer.to_string());
+        self.times[part - 1] = Some(time.to_string());
+        self.stars = self.stars.max(self.answers.iter().flatten().count() as u32);
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(title) = &self.title {
+            writeln!(f, "title: {}", title)?;
+        }
+        writeln!(f, "stars: {}", self.stars)?;
+        for part in 0..2 {
+            if let Some(answer) = &self.answers[part] {
+                writeln!(f, "part{}: {}", part + 1, answer)?;
+            }
+            if let Some(time) = &self.times[part] {
+                writeln!(f, "part{}_time: {}", part + 1, time)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        let mut entry = Answers::parse("title: Historian Hysteria\nstars: 0\n");
+        entry.record(2, "31", "1.20000ms");
+
+        assert_eq!(entry.stars, 1);
+        assert_eq!(Answers::parse(&entry.to_string()), entry);
+    }
+}
@@ -0,0 +1,47 @@
+/// A property of the puzzle input that a solution relies on. Days list theirs
+/// in an `ASSUMPTIONS` table so a bad input is reported instead of silently
+/// producing a wrong answer. Checks that depend on params are closures built
+/// once the params are known.
+pub struct Assumption<F = fn(&str) -> Result<(), String>> {
+    pub name: &'static str,
+    pub check: F,
+}
+
+/// Returns the first assumption `contents` violates, along with why.
+pub fn violated<F: Fn(&str) -> Result<(), String>>(contents: &str, assumptions: &[Assumption<F>]) -> Option<(&'static str, String)> {
+    return assumptions.iter().find_map(|assumption| {
+        (assumption.check)(contents).err().map(|reason| (assumption.name, reason))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn non_empty(contents: &str) -> Result<(), String> {
+        if contents.is_empty() {
+            Err("input is empty".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn violated_test() {
+        let assumptions = [Assumption { name: "non-empty", check: non_empty }];
+        assert_eq!(violated("abc", &assumptions), None);
+        assert_eq!(violated("", &assumptions), Some(("non-empty", "input is empty".to_string())));
+
+        // Checks can capture params
+        let limit = 2;
+        let check = |contents: &str| {
+            if contents.len() > limit {
+                Err(format!("longer than {}", limit))
+            } else {
+                Ok(())
+            }
+        };
+        let short = [Assumption { name: "short", check }];
+        assert_eq!(violated("abc", &short), Some(("short", "longer than 2".to_string())));
+    }
+}
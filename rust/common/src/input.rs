@@ -0,0 +1,91 @@
+use memmap2::Mmap;
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::str;
+
+/// A puzzle input, read once and shared by reference between both parts.
+/// Very large inputs can be memory-mapped instead of copied onto the heap.
+pub enum Input {
+    Owned(String),
+    Mapped(Mmap),
+}
+
+impl Input {
+    pub fn read(path: &Path) -> io::Result<Input> {
+        return Ok(Input::Owned(std::fs::read_to_string(path)?));
+    }
+
+    pub fn map(path: &Path) -> io::Result<Input> {
+        // Safety: inputs aren't expected to change while a solution runs
+        let map = unsafe { Mmap::map(&File::open(path)?)? };
+        str::from_utf8(&map).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        return Ok(Input::Mapped(map));
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            Input::Owned(contents) => contents,
+            // Safety: validated as UTF-8 when mapped
+            Input::Mapped(map) => unsafe { str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+/// An input for days that only ever look at one line at a time.
+pub enum Source {
+    Loaded(Input),
+    Streamed(PathBuf),
+}
+
+impl Source {
+    /// A fresh reader over the whole input, for days that don't split it into lines.
+    pub fn reader(&self) -> Box<dyn Read + '_> {
+        match self {
+            Source::Loaded(input) => Box::new(input.as_bytes()),
+            Source::Streamed(path) => Box::new(File::open(path)
+                .unwrap_or_else(|e| panic!("Should have been able to read {}: {}", path.display(), e))),
+        }
+    }
+
+    pub fn lines(&self) -> Box<dyn Iterator<Item = Cow<'_, str>> + '_> {
+        match self {
+            Source::Loaded(input) => Box::new(input.lines().map(Cow::Borrowed)),
+            Source::Streamed(path) => {
+                let file = File::open(path)
+                    .unwrap_or_else(|e| panic!("Should have been able to read {}: {}", path.display(), e));
+                Box::new(BufReader::new(file).lines().map(|line| Cow::Owned(line.unwrap())))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn map_test() {
+        let path = env::temp_dir().join("aoc_common_map_test.txt");
+        fs::write(&path, "3   4\n4   3\n").unwrap();
+
+        let mapped = Input::map(&path).unwrap();
+        assert_eq!(mapped.lines().count(), 2);
+        assert_eq!(&*mapped, &*Input::read(&path).unwrap());
+
+        let streamed = Source::Streamed(path.clone());
+        assert_eq!(Vec::from_iter(streamed.lines()), Vec::from_iter(mapped.lines()));
+
+        fs::write(&path, [0xff, 0xfe]).unwrap();
+        assert!(Input::map(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+#![allow(clippy::needless_return)]
+
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+
+mod answers;
+mod assumptions;
+mod input;
+pub mod overflow;
+mod variants;
+pub use answers::Answers;
+pub use assumptions::{violated, Assumption};
+pub use input::{Input, Source};
+pub use variants::{agree, print_timings, time_variants, Timing, Variant};
+
+/// Identifies a single puzzle. Every per-day crate is named `rust_<year>_<day>`,
+/// so the key is recovered from the package name instead of being hard-coded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+}
+
+impl Puzzle {
+    pub fn new(year: u32, day: u32) -> Puzzle {
+        return Puzzle { year, day };
+    }
+
+    pub fn from_package(name: &str) -> Puzzle {
+        let mut parts = name.rsplit('_');
+        let day = parts.next().and_then(|d| d.parse::<u32>().ok());
+        let year = parts.next().and_then(|y| y.parse::<u32>().ok());
+        match (year, day) {
+            (Some(year), Some(day)) => Puzzle { year, day },
+            _ => panic!("Package name {} should look like rust_<year>_<day>", name),
+        }
+    }
+
+    /// File name shared by every per-puzzle artifact, e.g. `2024_1.txt`.
+    pub fn file_name(&self, ext: &str) -> String {
+        return format!("{}.{}", self, ext);
+    }
+
+    /// Searches `start` and its ancestors for `Inputs/<year>_<day>.txt`.
+    /// `AOC_INPUTS` overrides the search with an explicit directory.
+    pub fn find_input(&self, start: &Path) -> Option<PathBuf> {
+        let name = self.file_name("txt");
+        if let Ok(dir) = env::var("AOC_INPUTS") {
+            return Some(Path::new(&dir).join(name));
+        }
+
+        return start.ancestors()
+            .map(|dir| dir.join("Inputs").join(&name))
+            .find(|path| path.is_file());
+    }
+
+    /// The input named on the command line, falling back to the `Inputs`
+    /// directory found by `find_input`.
+    pub fn input_path(&self) -> PathBuf {
+        return match env::args().nth(1) {
+            Some(arg) => PathBuf::from(arg),
+            None => {
+                let root = env::current_dir().unwrap();
+                self.find_input(&root).unwrap_or_else(|| root.join("Inputs").join(self.file_name("txt")))
+            }
+        };
+    }
+
+    /// Reads the whole input once so both parts can borrow it. With
+    /// `AOC_MMAP` set the file is memory-mapped instead of copied.
+    pub fn read_input(&self) -> Input {
+        let path = self.input_path();
+        let input = if env::var_os("AOC_MMAP").is_some() { Input::map(&path) } else { Input::read(&path) };
+        return input.unwrap_or_else(|e| panic!("Should have been able to read {}: {}", path.display(), e));
+    }
+
+    /// Where line-oriented days read their input from. With `AOC_STREAM` set
+    /// each part reads the file afresh rather than holding all of it.
+    pub fn source(&self) -> Source {
+        if env::var_os("AOC_STREAM").is_some() {
+            return Source::Streamed(self.input_path());
+        }
+        return Source::Loaded(self.read_input());
+    }
+
+    /// A tunable constant such as a grid size or step count, which
+    /// `AOC_PARAM_<NAME>` overrides so a day can be rerun with other values.
+    pub fn param<T: FromStr>(&self, name: &str, default: T) -> T {
+        let var = format!("AOC_PARAM_{}", name.to_uppercase());
+        return match env::var(&var) {
+            Ok(value) => value.parse::<T>().unwrap_or_else(|_| panic!("{} should be a valid {}, not {}", var, name, value)),
+            Err(_) => default,
+        };
+    }
+
+    /// Exits with a report naming the assumption `contents` violates, if any.
+    pub fn check<F: Fn(&str) -> Result<(), String>>(&self, contents: &str, assumptions: &[Assumption<F>]) {
+        if let Some((name, reason)) = violated(contents, assumptions) {
+            eprintln!("Input for {} violates assumption \"{}\": {}", self, name, reason);
+            process::exit(1);
+        }
+    }
+
+    /// When `AOC_VARIANTS` is set, times every variant instead of the usual
+    /// run and exits, failing if any part's variants disagree.
+    pub fn compare_variants<T: fmt::Display>(&self, contents: &str, variants: &[Variant<T>]) {
+        if env::var_os("AOC_VARIANTS").is_none() {
+            return;
+        }
+
+        let timings = time_variants(contents, variants);
+        print_timings(&timings);
+        process::exit(if agree(&timings) { 0 } else { 1 });
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}_{}", self.year, self.day)
+    }
+}
+
+/// The `Puzzle` for the crate this is expanded in.
+#[macro_export]
+macro_rules! puzzle {
+    () => {
+        $crate::Puzzle::from_package(env!("CARGO_PKG_NAME"))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn package_name_test() {
+        assert_eq!(Puzzle::from_package("rust_2024_17"), Puzzle::new(2024, 17));
+        assert_eq!(Puzzle::from_package("rust_2025_1").to_string(), "2025_1");
+    }
+
+    #[test]
+    fn param_test() {
+        let puzzle = Puzzle::new(2024, 11);
+        assert_eq!(puzzle.param("unset_for_test", 25), 25);
+        env::set_var("AOC_PARAM_BLINKS_FOR_TEST", "6");
+        assert_eq!(puzzle.param("blinks_for_test", 25), 6);
+    }
+
+    #[test]
+    fn ordering_test() {
+        assert!(Puzzle::new(2024, 25) < Puzzle::new(2025, 1));
+        assert!(Puzzle::new(2024, 2) < Puzzle::new(2024, 10));
+    }
+}
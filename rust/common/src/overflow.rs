@@ -0,0 +1,61 @@
+//! Arithmetic for accumulating answers. Release builds normally wrap on
+//! overflow; with the `checked` feature these report it instead, naming the
+//! operation and the line in the day that overflowed.
+
+#[track_caller]
+fn overflow(a: impl std::fmt::Display, op: &str, b: impl std::fmt::Display) -> ! {
+    panic!("Overflow computing {} {} {}", a, op, b);
+}
+
+#[track_caller]
+pub fn add(a: i64, b: i64) -> i64 {
+    if cfg!(feature = "checked") {
+        return a.checked_add(b).unwrap_or_else(|| overflow(a, "+", b));
+    }
+    return a + b;
+}
+
+#[track_caller]
+pub fn mul(a: i64, b: i64) -> i64 {
+    if cfg!(feature = "checked") {
+        return a.checked_mul(b).unwrap_or_else(|| overflow(a, "*", b));
+    }
+    return a * b;
+}
+
+#[track_caller]
+pub fn sum(values: impl IntoIterator<Item = i64>) -> i64 {
+    return values.into_iter().fold(0, add);
+}
+
+#[track_caller]
+pub fn product(values: impl IntoIterator<Item = i64>) -> i64 {
+    return values.into_iter().fold(1, mul);
+}
+
+/// Rounds a float to the nearest integer, checking that it was exact enough
+/// to trust: beyond 2^53 an `f64` can't tell neighbouring integers apart.
+#[track_caller]
+pub fn round(value: f64) -> i64 {
+    if cfg!(feature = "checked") && value.abs() >= (1u64 << 53) as f64 {
+        panic!("Overflow rounding {}: too large to represent exactly", value);
+    }
+    return value.round() as i64;
+}
+
+#[cfg(all(test, feature = "checked"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "Overflow computing 9223372036854775807 + 1")]
+    fn add_test() {
+        add(i64::MAX, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow computing")]
+    fn product_test() {
+        product([1 << 40, 1 << 30]);
+    }
+}
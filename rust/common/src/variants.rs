@@ -0,0 +1,76 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// One named implementation of a part. Days with more than one approach list
+/// them in a `VARIANTS` table so they can be checked against each other.
+pub struct Variant<T> {
+    pub part: usize,
+    pub name: &'static str,
+    pub solve: fn(&str) -> T,
+}
+
+#[derive(Clone, Debug)]
+pub struct Timing {
+    pub part: usize,
+    pub name: &'static str,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+pub fn time_variants<T: Display>(contents: &str, variants: &[Variant<T>]) -> Vec<Timing> {
+    return variants.iter().map(|variant| {
+        let timer = Instant::now();
+        let answer = (variant.solve)(contents).to_string();
+        Timing { part: variant.part, name: variant.name, answer, elapsed: timer.elapsed() }
+    }).collect();
+}
+
+/// Whether every variant of each part produced the same answer.
+pub fn agree(timings: &[Timing]) -> bool {
+    return timings.iter().all(|t| {
+        timings.iter().filter(|o| o.part == t.part).all(|o| o.answer == t.answer)
+    });
+}
+
+/// Prints each part's variants with their time relative to the fastest.
+pub fn print_timings(timings: &[Timing]) {
+    let mut parts: Vec<usize> = timings.iter().map(|t| t.part).collect();
+    parts.sort();
+    parts.dedup();
+
+    for part in parts {
+        let variants: Vec<&Timing> = timings.iter().filter(|t| t.part == part).collect();
+        let fastest = variants.iter().map(|t| t.elapsed).min().unwrap().as_secs_f64().max(f64::MIN_POSITIVE);
+        println!("\nPart {}:", part);
+        for t in &variants {
+            let agrees = if t.answer == variants[0].answer { "" } else { "  MISMATCH" };
+            println!(
+                "{:<20} {:<16} {:>12.5?} {:>8.2}x{}",
+                t.name, t.answer, t.elapsed, t.elapsed.as_secs_f64() / fastest, agrees
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(contents: &str) -> i64 {
+        return contents.lines().count() as i64;
+    }
+
+    fn count_newlines(contents: &str) -> i64 {
+        return contents.matches('\n').count() as i64;
+    }
+
+    #[test]
+    fn agree_test() {
+        let variants = [
+            Variant { part: 1, name: "lines", solve: count },
+            Variant { part: 1, name: "newlines", solve: count_newlines },
+        ];
+        assert!(agree(&time_variants("a\nb\n", &variants)));
+        assert!(!agree(&time_variants("a\nb", &variants)));
+    }
+}
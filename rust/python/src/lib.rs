@@ -0,0 +1,291 @@
+//! Python bindings for the Rust solutions, built with `maturin develop --release`
+//! from this directory. Each day is exposed as a submodule of `aoc_rust`:
+//!
+//! ```python
+//! import aoc_rust
+//! aoc_rust.day1.part1(open("Inputs/2024_1.txt").read())
+//! aoc_rust.day1.parse(open("Inputs/2024_1.txt").read())
+//! ```
+//!
+//! Each day's solution lives in its crate's library, which these bindings
+//! depend on. Bad input raises `ValueError`, whether it breaks one of the
+//! day's assumptions or makes the solution panic.
+
+use aoc_common::Assumption;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
+
+use rust_2024_1 as day1;
+use rust_2024_2 as day2;
+use rust_2024_3 as day3;
+use rust_2024_4 as day4;
+use rust_2024_5 as day5;
+use rust_2024_6 as day6;
+use rust_2024_7 as day7;
+use rust_2024_8 as day8;
+use rust_2024_9 as day9;
+use rust_2024_10 as day10;
+use rust_2024_11 as day11;
+use rust_2024_12 as day12;
+use rust_2024_13 as day13;
+use rust_2024_14 as day14;
+use rust_2024_15 as day15;
+use rust_2024_16 as day16;
+use rust_2024_17 as day17;
+use rust_2024_18 as day18;
+use rust_2024_19 as day19;
+use rust_2024_20 as day20;
+use rust_2024_21 as day21;
+use rust_2024_22 as day22;
+use rust_2024_23 as day23;
+use rust_2024_24 as day24;
+use rust_2024_25 as day25;
+
+/// Fails with the first assumption `contents` violates, if any.
+fn check<F: Fn(&str) -> Result<(), String>>(contents: &str, assumptions: &[Assumption<F>]) -> PyResult<()> {
+    match aoc_common::violated(contents, assumptions) {
+        Some((name, reason)) => Err(PyValueError::new_err(format!("input violates assumption \"{}\": {}", name, reason))),
+        None => Ok(()),
+    }
+}
+
+/// Runs part of a solution, raising its panic message rather than aborting.
+fn solve<T>(f: impl FnOnce() -> T) -> PyResult<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => payload.downcast_ref::<&str>().map_or("solution panicked".to_string(), |message| message.to_string()),
+        };
+        PyValueError::new_err(message)
+    })
+}
+
+/// Wraps a day's `part1`/`part2` as a Python submodule. Extra arguments after
+/// the input (e.g. day 11's blink count) are passed straight through, and
+/// each `assuming` table is checked before either part runs. The day's
+/// `parse` is exposed too, converted by the given closure when its result
+/// isn't already a Python type.
+macro_rules! solution {
+    ($day:ident, ($($a1:ident: $t1:ty),*) -> $r1:ty, ($($a2:ident: $t2:ty),*) -> $r2:ty, parse -> $p:ty $(, assuming $assumptions:expr)*) => {
+        solution!($day, ($($a1: $t1),*) -> $r1, ($($a2: $t2),*) -> $r2, parse -> $p = |contents| crate::$day::parse(contents) $(, assuming $assumptions)*);
+    };
+    ($day:ident, ($($a1:ident: $t1:ty),*) -> $r1:ty, ($($a2:ident: $t2:ty),*) -> $r2:ty, parse -> $p:ty = |$c:ident| $parse:expr $(, assuming $assumptions:expr)*) => {
+        pub mod $day {
+            use pyo3::prelude::*;
+            #[allow(unused_imports)]
+            use std::collections::{HashMap, HashSet};
+            #[allow(unused_imports)]
+            use aoc_common::Assumption;
+
+            #[pyfunction]
+            fn parse($c: &str) -> PyResult<$p> {
+                crate::solve(|| $parse)
+            }
+
+            #[pyfunction]
+            fn part1(contents: &str $(, $a1: $t1)*) -> PyResult<$r1> {
+                $(crate::check(contents, $assumptions)?;)*
+                crate::solve(|| crate::$day::part1(contents $(, $a1)*))
+            }
+
+            #[pyfunction]
+            fn part2(contents: &str $(, $a2: $t2)*) -> PyResult<$r2> {
+                $(crate::check(contents, $assumptions)?;)*
+                crate::solve(|| crate::$day::part2(contents $(, $a2)*))
+            }
+
+            pub fn register<'py>(parent: &Bound<'py, PyModule>) -> PyResult<Bound<'py, PyModule>> {
+                let m = PyModule::new(parent.py(), stringify!($day))?;
+                m.add_function(wrap_pyfunction!(parse, &m)?)?;
+                m.add_function(wrap_pyfunction!(part1, &m)?)?;
+                m.add_function(wrap_pyfunction!(part2, &m)?)?;
+                parent.add_submodule(&m)?;
+                Ok(m)
+            }
+        }
+    };
+}
+
+mod bindings {
+    solution!(day1, () -> i64, () -> i64, parse -> Vec<Vec<i64>>);
+    solution!(day2, () -> i64, () -> i64, parse -> Vec<Vec<i64>>);
+    solution!(day3, () -> i64, () -> i64, parse -> Vec<crate::PyCall> = |contents| {
+        Vec::from_iter(crate::day3::parse(contents).iter().map(crate::PyCall::new))
+    });
+    solution!(day4, () -> i64, () -> i64, parse -> Vec<String> = |contents| {
+        Vec::from_iter(crate::day4::parse(contents).iter().map(|row| String::from_utf8_lossy(row).into_owned()))
+    });
+    solution!(day5, () -> i64, () -> i64, parse -> (Vec<(i64, i64)>, Vec<Vec<i64>>) = |contents| {
+        let (rules, updates) = crate::day5::parse(contents);
+        (rules.pairs(), updates)
+    }, assuming crate::day5::ASSUMPTIONS);
+    solution!(day6, () -> i64, () -> i64, parse -> (usize, usize, Vec<bool>, Vec<(usize, usize)>) = |contents| {
+        // `solve` raises the reason as a ValueError
+        let lab = crate::day6::Lab::parse(contents).unwrap_or_else(|reason| panic!("{}", reason));
+        (lab.width, lab.height, lab.open, lab.guards)
+    }, assuming crate::day6::ASSUMPTIONS);
+    solution!(day7, () -> i64, () -> i64, parse -> Vec<(i64, Vec<i64>)>);
+    solution!(day8, () -> i64, () -> i64, parse -> HashMap<char, HashSet<(i64, i64)>>, assuming crate::day8::ASSUMPTIONS);
+    solution!(day9, () -> i64, () -> i64, parse -> Vec<i64>);
+    solution!(day10, () -> i64, () -> i64, parse -> HashMap<(i64, i64), i64>);
+    solution!(day11, (blinks: i64) -> i64, () -> i64, parse -> HashMap<i64, i64>);
+    solution!(day12, () -> i64, () -> i64, parse -> HashMap<(i64, i64), char>);
+    solution!(day13, () -> i64, () -> i64, parse -> Vec<[(f64, f64); 3]> = |contents| {
+        Vec::from_iter(crate::day13::parse(contents).into_iter().map(|c| [c.a, c.b, c.prize]))
+    }, assuming crate::day13::ASSUMPTIONS);
+    solution!(day14, (width: i64, height: i64) -> i64, (width: i64, height: i64) -> i64, parse -> Vec<((i64, i64), (i64, i64))> = |contents| {
+        Vec::from_iter(crate::day14::parse(contents).into_iter().map(|r| ((r.p_x, r.p_y), (r.v_x, r.v_y))))
+    },
+        assuming &[Assumption { name: "prime dimensions", check: |_: &str| crate::day14::prime_dimensions(width, height) }],
+        assuming &[Assumption { name: "robots start on the grid", check: |contents: &str| crate::day14::robots_on_grid(contents, width, height) }]);
+    solution!(day15, () -> i64, () -> i64, parse -> crate::day15::Warehouse);
+    solution!(day16, () -> i64, () -> i64, parse -> crate::day16::Maze);
+    solution!(day17, () -> String, () -> Option<i64>, parse -> crate::PyProgram = |contents| crate::PyProgram(crate::day17::parse(contents)));
+    solution!(day18, (max_coord: i64, sim_bytes: usize) -> i64, (max_coord: i64) -> String, parse -> Vec<(i64, i64)>);
+    solution!(day19, () -> i64, () -> i64, parse -> (Vec<String>, Vec<String>));
+    solution!(day20, (min_saved: i64) -> i64, (min_saved: i64) -> i64, parse -> crate::day20::Track, assuming crate::day20::ASSUMPTIONS);
+    solution!(day21, () -> i64, () -> i64, parse -> Vec<(String, i64)> = |contents| {
+        Vec::from_iter(crate::day21::parse(contents).into_iter().map(|(code, value)| (code.to_string(), value)))
+    });
+    solution!(day22, () -> i64, () -> i64, parse -> Vec<i64>);
+    solution!(day23, () -> i64, () -> String, parse -> HashMap<String, HashSet<String>>);
+    solution!(day24, () -> i64, () -> String, parse -> crate::day24::Circuit);
+    solution!(day25, () -> i64, () -> String, parse -> (Vec<Vec<i64>>, Vec<Vec<i64>>), assuming crate::day25::ASSUMPTIONS);
+}
+
+/// One of day 3's calls, with its instruction's name, its arguments (numbers
+/// or nested calls) and its byte span in memory.
+#[pyclass(name = "Call", get_all, skip_from_py_object)]
+#[derive(Clone)]
+struct PyCall {
+    name: &'static str,
+    args: Vec<PyArg>,
+    span: (usize, usize),
+}
+
+#[derive(Clone, IntoPyObject)]
+enum PyArg {
+    Number(i64),
+    Call(PyCall),
+}
+
+impl PyCall {
+    fn new(call: &day3::Call) -> PyCall {
+        // Indices into the instruction table day 3's `parse` uses
+        let names = [day3::MUL.name, day3::DO.name, day3::DONT.name];
+        let args = Vec::from_iter(call.args.iter().map(|arg| match arg {
+            day3::Arg::Number(n) => PyArg::Number(*n),
+            day3::Arg::Call(nested) => PyArg::Call(PyCall::new(nested)),
+        }));
+        PyCall { name: names[call.instruction], args, span: (call.span.start, call.span.end) }
+    }
+}
+
+#[pymethods]
+impl PyCall {
+    fn __repr__(&self) -> String {
+        format!("Call(name={:?}, span={:?})", self.name, self.span)
+    }
+}
+
+/// Day 17's 3-bit computer, with registers readable and writable from Python.
+#[pyclass(name = "Program")]
+struct PyProgram(day17::Program);
+
+#[pymethods]
+impl PyProgram {
+    #[new]
+    fn new(contents: &str) -> PyProgram {
+        PyProgram(day17::Program::new(contents))
+    }
+
+    #[getter]
+    fn a(&self) -> i64 {
+        self.0.a
+    }
+
+    #[setter]
+    fn set_a(&mut self, a: i64) {
+        self.0.a = a;
+    }
+
+    #[getter]
+    fn b(&self) -> i64 {
+        self.0.b
+    }
+
+    #[setter]
+    fn set_b(&mut self, b: i64) {
+        self.0.b = b;
+    }
+
+    #[getter]
+    fn c(&self) -> i64 {
+        self.0.c
+    }
+
+    #[setter]
+    fn set_c(&mut self, c: i64) {
+        self.0.c = c;
+    }
+
+    #[getter]
+    fn instructions(&self) -> Vec<i8> {
+        self.0.instructions.clone()
+    }
+
+    #[getter]
+    fn output(&self) -> Vec<i64> {
+        self.0.output.clone()
+    }
+
+    fn run(&mut self) {
+        self.0.run();
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Program(a={}, b={}, c={}, instructions={:?})", self.0.a, self.0.b, self.0.c, self.0.instructions)
+    }
+}
+
+/// Every maximal clique of day 23's network, given each computer's neighbours.
+#[pyfunction]
+fn bron_kerbosch(connections: HashMap<String, HashSet<String>>) -> Vec<HashSet<String>> {
+    let mut candidates: HashSet<String> = connections.keys().cloned().collect();
+    return day23::bron_kerbosch(&connections, HashSet::new(), &mut candidates, &mut HashSet::new());
+}
+
+#[pymodule]
+fn aoc_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    bindings::day1::register(m)?;
+    bindings::day2::register(m)?;
+    let day3 = bindings::day3::register(m)?;
+    day3.add_class::<PyCall>()?;
+    bindings::day4::register(m)?;
+    bindings::day5::register(m)?;
+    bindings::day6::register(m)?;
+    bindings::day7::register(m)?;
+    bindings::day8::register(m)?;
+    bindings::day9::register(m)?;
+    bindings::day10::register(m)?;
+    bindings::day11::register(m)?;
+    bindings::day12::register(m)?;
+    bindings::day13::register(m)?;
+    bindings::day14::register(m)?;
+    bindings::day15::register(m)?;
+    bindings::day16::register(m)?;
+    let day17 = bindings::day17::register(m)?;
+    day17.add_class::<PyProgram>()?;
+    bindings::day18::register(m)?;
+    bindings::day19::register(m)?;
+    bindings::day20::register(m)?;
+    bindings::day21::register(m)?;
+    bindings::day22::register(m)?;
+    let day23 = bindings::day23::register(m)?;
+    day23.add_function(wrap_pyfunction!(bron_kerbosch, &day23)?)?;
+    bindings::day24::register(m)?;
+    bindings::day25::register(m)?;
+    Ok(())
+}